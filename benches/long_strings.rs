@@ -0,0 +1,62 @@
+//! Times every algorithm on long inputs, such as 5 KB product descriptions.
+//!
+//! Run with `cargo bench --bench long_strings`.
+
+extern crate string_distance;
+
+use std::time::{Duration, Instant};
+use string_distance::*;
+
+/// Builds a deterministic pseudo-random string of `len` chars, with a few non-ASCII characters.
+fn description(len:usize, seed:u64) -> String
+{
+    let alphabet:Vec<char> = "abcdefghijklmnopqrstuvwxyz      éü".chars().collect();
+    let mut state = seed;
+    (0..len).map(|_| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        alphabet[((state >> 33) as usize) % alphabet.len()]
+    }).collect()
+}
+
+fn time<T, F: Fn() -> T>(name:&str, runs:u32, f:F)
+{
+    let mut total = Duration::new(0, 0);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let _ = f();
+        total += start.elapsed();
+    }
+    let avg = total / runs;
+    println!("{:<40} {:>10.3} ms", name, avg.as_secs() as f64 * 1e3 + f64::from(avg.subsec_nanos()) / 1e6);
+}
+
+fn main()
+{
+    let len = 5000;
+    let str1 = description(len, 1);
+    let str2 = description(len, 2);
+    let (s1, s2) = (str1.as_str(), str2.as_str());
+
+    println!("{} chars", len);
+    time("hamming_distance", 10, || hamming_distance(s1, s2));
+    time("jaro_simularity", 3, || jaro_simularity(s1, s2, None));
+    time("jaro_winkler_simularity", 3, || jaro_winkler_simularity(s1, s2, None));
+    time("levenshtein_distance", 3, || levenshtein_distance(s1, s2));
+    time("osa_distance", 3, || osa_distance(s1, s2));
+    time("levenshtein_edit_ops", 1, || levenshtein_edit_ops(s1, s2));
+    time("osa_edit_ops", 1, || osa_edit_ops(s1, s2));
+    time("damerau_levenshtein_distance", 1, || damerau_levenshtein_distance(s1, s2));
+
+    let costs = OperationCosts::default();
+    time("weighted_levenshtein_distance", 3, || weighted_levenshtein_distance(s1, s2, &costs));
+    time("weighted_osa_distance", 3, || weighted_osa_distance(s1, s2, &costs));
+    time("levenshtein_distance_bounded", 10, || levenshtein_distance_bounded(s1, s2, 100));
+    time("lcs::longest", 3, || lcs::longest(s1, s2));
+    time("lcs::longest_first", 10, || lcs::longest_first(s1, s2));
+    time("lcs::longest_shared_by_all", 3, || lcs::longest_shared_by_all(&[s1, s2]));
+
+    let mb = 1 << 20;
+    let (large1, large2) = (description(mb, 3), description(mb, 4));
+    println!("{} chars", mb);
+    time("lcs::longest_first", 1, || lcs::longest_first(&large1, &large2));
+}
@@ -0,0 +1,67 @@
+//! # String Distance
+//! A collection of string distance and simularity metrics.
+//!
+//! ## Example
+//! ```
+//! extern crate string_distance;
+//! use string_distance::*;
+//!
+//! let ld = levenshtein_distance("paul", "pual");
+//! let jw = jaro_winkler_simularity("paul", "pual", None);
+//! println!("Levenshtein: {}, Jaro Winkler: {}", ld, jw);
+//! ```
+
+extern crate unicode_segmentation;
+extern crate unicode_normalization;
+extern crate caseless;
+
+#[macro_use]
+mod string_dist;
+
+// Edit-Based Simularities
+pub use string_dist::{hamming_distance, hamming_distance_by_unit};
+pub use string_dist::{jaccard_simularity, sorensen_dice_simularity, overlap_coefficient, tversky_index};
+pub use string_dist::jaro_simularity;
+pub use string_dist::jaro_winkler_simularity;
+pub use string_dist::{jaro_simularity_by_unit, jaro_winkler_simularity_by_unit};
+pub use string_dist::strcmp95_simularity;
+pub use string_dist::{JWOptions, JWOptionsBuilder, JWOptionsError};
+pub use string_dist::levenshtein_distance;
+pub use string_dist::levenshtein_distance_explained;
+pub use string_dist::levenshtein_simularity;
+pub use string_dist::osa_distance;
+pub use string_dist::osa_distance_explained;
+pub use string_dist::damerau_levenshtein_distance;
+pub use string_dist::damerau_levenshtein_distance_explained;
+pub use string_dist::levenshtein_edit_ops;
+pub use string_dist::osa_edit_ops;
+pub use string_dist::damerau_levenshtein_edit_ops;
+pub use string_dist::{levenshtein_distance_by_unit, osa_distance_by_unit, damerau_levenshtein_distance_by_unit};
+pub use string_dist::{EditOp, EditKind};
+pub use string_dist::{CostModel, OperationCosts};
+pub use string_dist::{KeyboardLayout, KeyboardCosts};
+pub use string_dist::{OcrCosts, ocr_distance};
+pub use string_dist::{weighted_levenshtein_distance, weighted_osa_distance, weighted_damerau_levenshtein_distance};
+pub use string_dist::{Confusion, generalized_levenshtein_distance};
+pub use string_dist::{levenshtein_distance_bounded, osa_distance_bounded, damerau_levenshtein_distance_bounded};
+pub use string_dist::distance_to_simularity;
+pub use string_dist::Unit;
+pub use string_dist::{Normalizer, NormalizationForm, Normalized};
+pub use string_dist::{DistMatrix, CompactDistMatrix};
+pub use string_dist::{Explain, PrintExplain};
+pub use string_dist::{StringMetric, Hamming, Levenshtein, OptimalStringAlignment, DamerauLevenshtein, Jaro, JaroWinkler, Strcmp95};
+pub use string_dist::{LongestCommonSubsequence, LongestCommonSubstring, RatcliffObershelp};
+pub use string_dist::{Jaccard, SorensenDice, Overlap, Tversky, Cosine, TfIdf, SoftTfIdf};
+
+// Sequence-Based Simularities
+pub use string_dist::subsequence;
+pub use string_dist::{ratcliff_obershelp_simularity, ratcliff_obershelp_matching_blocks};
+
+// Token-Based Simularities
+pub use string_dist::lcs;
+pub use string_dist::{Tokenizer, TokenBag, TokenBagIter, Multiplicity, QGramTokenizer, WhitespaceTokenizer, PunctuationTokenizer, UnicodeWordTokenizer, WordNGramTokenizer};
+pub use string_dist::{Profile, cosine_simularity};
+pub use string_dist::{Corpus, CorpusError, tfidf_simularity};
+
+// Hybrid Simularities
+pub use string_dist::soft_tfidf_simularity;
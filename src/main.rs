@@ -1,11 +1,6 @@
-#![allow(unused_variables)]
-#![allow(dead_code)]
+extern crate string_distance;
 
-mod string_dist;
-
-use string_dist::lcs::distance as lcs;
-use string_dist::lcs::distance2 as lcs2;
-use string_dist::*;
+use string_distance::*;
 
 fn main()
 {
@@ -19,10 +14,10 @@ fn main()
     let j = jaro_simularity( str1, str2, None );
     let jw = jaro_winkler_simularity( str1, str2, None );
     
-    let lcs_hash = lcs( str1, str2 );
-    let lcs_vec = lcs2( str1, str2 );
-    let lcs:u8 = if lcs_hash.len() > 0 {*(lcs_hash.values().next().unwrap())} else {0u8};
-    let lcs2:usize = if lcs_vec.len() > 0 {lcs_vec.get(0).unwrap().str.clone().chars().count()} else {0};
+    let lcs = lcs::longest( str1, str2 );
+    let lcs_len = lcs::length( str1, str2 );
+    let lcs_seq = subsequence::longest( str1, str2 );
+    let indel = subsequence::distance( str1, str2 );
     
     // Edit-Based Distances
     println!("Hamming Distance: {}", hd);
@@ -36,8 +31,10 @@ fn main()
 
 
 
-    // Token-Based Distances
+    // Sequence-Based Distances
 
-    println!("Longest Common Substring Distance: {}", lcs);
-    println!("Longest Common Substring Distance2: {}", lcs2);
-}
\ No newline at end of file
+    println!("Longest Common Substrings: {:?}", lcs);
+    println!("Longest Common Substring Length: {}", lcs_len);
+    println!("Longest Common Subsequence: {}", lcs_seq);
+    println!("LCS (Indel) Distance: {}", indel);
+}
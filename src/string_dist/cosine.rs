@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::iter::FromIterator;
+use super::token::{Tokenizer, TokenBag};
+
+/// # Profile
+/// A string as a vector of token weights, with its precomputed length (norm). By default the weight of a token
+/// is its term frequency, the number of times it occurs.
+///
+/// Building a profile tokenizes the string once, so when one query is compared to many stored strings,
+/// the stored strings' profiles can be built ahead of time and reused for every query.
+///
+/// ## Example
+/// ```
+/// use string_distance::{Profile, QGramTokenizer};
+///
+/// let bigrams = QGramTokenizer::new(2).padded();
+/// let stored:Vec<Profile> = ["Jon Smith", "John Smyth", "Jane Doe"].iter().map(|s| Profile::new(s, &bigrams)).collect();
+///
+/// let query = Profile::new("Jonathan Smith", &bigrams);
+/// let scores:Vec<f64> = stored.iter().map(|p| query.cosine_simularity(p)).collect();
+/// assert!(scores[0] > scores[1] && scores[1] > scores[2]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Profile
+{
+    weights:HashMap<String, f64>,
+    /// The sum of the squared weights
+    norm_squared:f64
+}
+
+impl Profile
+{
+    /// The term frequency profile of `s`.
+    pub fn new(s:&str, tokenizer:&dyn Tokenizer) -> Profile {
+        Profile::from_bag(&tokenizer.bag(s))
+    }
+
+    /// The term frequency profile of the tokens in `bag`.
+    pub fn from_bag(bag:&TokenBag) -> Profile {
+        bag.iter().map(|(token, count)| (token.to_string(), count as f64)).collect()
+    }
+
+    /// The weight of `token`, 0.0 if it is not in the profile.
+    pub fn weight(&self, token:&str) -> f64 {
+        self.weights.get(token).cloned().unwrap_or(0.0)
+    }
+
+    /// The Euclidean length of the vector of weights.
+    pub fn norm(&self) -> f64 {
+        self.norm_squared.sqrt()
+    }
+
+    /// The number of distinct tokens.
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    /// Every token and its weight, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item=(&str, f64)> {
+        self.weights.iter().map(|(token, &weight)| (token.as_str(), weight))
+    }
+
+    /// The dot product of both vectors of weights.
+    pub fn dot(&self, other:&Profile) -> f64 {
+        let (smaller, larger) = if self.len() <= other.len() { (self, other) } else { (other, self) };
+        smaller.iter().map(|(token, weight)| weight * larger.weight(token)).sum()
+    }
+
+    /// The cosine of the angle between both vectors of weights, 0.0 <= simularity <= 1.0.
+    /// Two empty profiles are identical, an empty profile shares nothing with any other.
+    pub fn cosine_simularity(&self, other:&Profile) -> f64 {
+        if self.is_empty() && other.is_empty() {
+            return 1.0;
+        }
+        // sqrt(|A|² |B|²) rather than |A| |B|, so that identical profiles are exactly 1.0
+        let norms = (self.norm_squared * other.norm_squared).sqrt();
+        if norms == 0.0 {
+            return 0.0;
+        }
+        (self.dot(other) / norms).min(1.0)
+    }
+}
+
+impl FromIterator<(String, f64)> for Profile
+{
+    /// Builds a profile from token weights, the weights of a repeated token are added.
+    /// Weights must be finite and at least 0.0, so that simularities stay within 0.0 to 1.0.
+    fn from_iter<I: IntoIterator<Item=(String, f64)>>(weights:I) -> Profile {
+        let mut profile = Profile::default();
+        for (token, weight) in weights {
+            assert!(weight.is_finite() && weight >= 0.0, "the weight of a token must be finite and at least 0.0");
+            *profile.weights.entry(token).or_insert(0.0) += weight;
+        }
+        profile.norm_squared = profile.weights.values().map(|w| w * w).sum();
+        profile
+    }
+}
+
+/// # Cosine Simularity
+/// Compares the term frequency vectors of both strings by the cosine of the angle between them,
+/// `A · B / (|A| |B|)`. Tokens that occur more often in both strings count for more, and unlike Jaccard
+/// the result does not depend on how many times longer one string is than the other.
+///
+/// To compare one string to many, build a `Profile` of each once and use `Profile::cosine_simularity`.
+///
+/// See - https://en.wikipedia.org/wiki/Cosine_similarity
+///
+/// ## Example
+/// ```
+/// use string_distance::{cosine_simularity, WhitespaceTokenizer};
+///
+/// assert_eq!(cosine_simularity("a b", "a b a b", &WhitespaceTokenizer), 1.0);
+/// assert_eq!(cosine_simularity("a a b", "a c", &WhitespaceTokenizer), 2.0 / 10f64.sqrt());
+/// ```
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `tokenizer` - Splits the strings into tokens
+/// * -> The Cosine Simularity, 0.0 <= simularity <= 1.0. Two strings without tokens are identical
+pub fn cosine_simularity(str1:&str, str2:&str, tokenizer:&dyn Tokenizer) -> f64
+{
+    Profile::new(str1, tokenizer).cosine_simularity(&Profile::new(str2, tokenizer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::token::{QGramTokenizer, WhitespaceTokenizer};
+
+    #[test]
+    fn empty_profiles() {
+        assert_eq!(cosine_simularity("", "", &WhitespaceTokenizer), 1.0);
+        assert_eq!(cosine_simularity("a", " ", &WhitespaceTokenizer), 0.0);
+        assert_eq!(Profile::new("", &WhitespaceTokenizer).norm(), 0.0);
+    }
+
+    #[test]
+    fn identical_and_disjoint() {
+        let bigrams = QGramTokenizer::new(2);
+        assert_eq!(cosine_simularity("Jonathan", "Jonathan", &bigrams), 1.0);
+        assert_eq!(cosine_simularity("abc", "xyz", &bigrams), 0.0);
+    }
+
+    #[test]
+    fn term_frequencies() {
+        let p = Profile::new("to be or not to be", &WhitespaceTokenizer);
+        assert_eq!((p.weight("to"), p.weight("or"), p.weight("xx")), (2.0, 1.0, 0.0));
+        assert_eq!(p.norm(), 10f64.sqrt());
+        assert_eq!(p.len(), 4);
+    }
+
+    #[test]
+    fn profiles_are_reusable() {
+        let bigrams = QGramTokenizer::new(2).padded();
+        let stored = Profile::new("Jonathan Smith", &bigrams);
+        for query in ["Jonathon Smyth", "Smith, Jonathan", "Jane Doe"].iter() {
+            assert_eq!(Profile::new(query, &bigrams).cosine_simularity(&stored), cosine_simularity(query, "Jonathan Smith", &bigrams));
+        }
+    }
+
+    #[test]
+    fn weights_are_summed() {
+        let p:Profile = vec![("a".to_string(), 3.0), ("b".to_string(), 1.0), ("a".to_string(), 1.0)].into_iter().collect();
+        assert_eq!(p.weight("a"), 4.0);
+        assert_eq!(p.norm(), 17f64.sqrt());
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_weight() {
+        let _:Profile = vec![("a".to_string(), -1.0)].into_iter().collect();
+    }
+
+    #[test]
+    #[should_panic]
+    fn infinite_weight() {
+        let _:Profile = vec![("a".to_string(), f64::INFINITY)].into_iter().collect();
+    }
+}
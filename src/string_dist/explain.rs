@@ -0,0 +1,93 @@
+use std::fmt::Debug;
+use super::levenshtein::DistMatrix;
+
+/// # Explain
+/// An opt-in observer that receives the intermediate state of a calculation.
+/// The plain distance functions are silent, their `*_explained` variants report to an `Explain`
+/// implementation so the computed matrix or matches can be logged, rendered or inspected.
+///
+/// Both methods default to doing nothing, so an implementation only needs the hooks it is interested in.
+///
+/// ## Example
+/// ```
+/// use string_distance::{Explain, DistMatrix, levenshtein_distance_explained};
+///
+/// struct LastCell(usize);
+/// impl Explain for LastCell {
+///     fn matrix(&mut self, _algorithm:&str, matrix:&DistMatrix<usize>) {
+///         self.0 = *matrix.get_last();
+///     }
+/// }
+///
+/// let mut explain = LastCell(0);
+/// let dist = levenshtein_distance_explained("paul", "pual", &mut explain);
+/// assert_eq!(explain.0, dist);
+/// ```
+pub trait Explain
+{
+    /// Receives the completed distance matrix of a matrix based algorithm.
+    fn matrix(&mut self, _algorithm:&str, _matrix:&DistMatrix<usize>) {}
+
+    /// Receives any other intermediate state, such as the matches found by a substring search.
+    fn state(&mut self, _algorithm:&str, _state:&dyn Debug) {}
+}
+
+/// Prints the intermediate state of every calculation to stdout.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PrintExplain;
+
+impl Explain for PrintExplain
+{
+    fn matrix(&mut self, algorithm:&str, matrix:&DistMatrix<usize>) {
+        println!("{} Calc:\n{:?}\n", algorithm, matrix);
+    }
+
+    fn state(&mut self, algorithm:&str, state:&dyn Debug) {
+        println!("{} Calc:\n{:?}\n", algorithm, state);
+    }
+}
+
+/// Discards everything, used by the plain (silent) distance functions.
+pub struct Silent;
+
+impl Explain for Silent {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::levenshtein::levenshtein::{osa_distance_explained, damerau_levenshtein_distance_explained};
+    use super::super::lcs;
+
+    #[derive(Default)]
+    struct Recorder {
+        algorithms:Vec<String>,
+        last:Option<usize>
+    }
+    impl Explain for Recorder {
+        fn matrix(&mut self, algorithm:&str, matrix:&DistMatrix<usize>) {
+            self.algorithms.push(algorithm.to_string());
+            self.last = Some(*matrix.get_last());
+        }
+        fn state(&mut self, algorithm:&str, _state:&dyn Debug) {
+            self.algorithms.push(algorithm.to_string());
+        }
+    }
+
+    #[test]
+    fn matrix_is_reported() {
+        let mut r = Recorder::default();
+        let d = osa_distance_explained("paul", "pual", &mut r);
+        assert_eq!(r.last, Some(d));
+        let d = damerau_levenshtein_distance_explained("paul", "pual", &mut r);
+        assert_eq!(r.last, Some(d));
+        assert_eq!(r.algorithms, vec!["OSA", "Damerau Levenshtein"]);
+    }
+
+    #[test]
+    fn state_is_reported() {
+        let mut r = Recorder::default();
+        lcs::longest_explained("failuree", "faluiere", &mut r);
+        assert_eq!(r.algorithms, vec!["Longest Common Substring"]);
+        assert_eq!(r.last, None);
+    }
+}
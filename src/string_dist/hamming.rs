@@ -1,4 +1,4 @@
-use super::helpers::{StringHelpers};
+use super::unit::Unit;
 
 /// # Hamming Distance 
 /// Calculates the string distance using the Hamming Distance algorithm.
@@ -13,11 +13,11 @@ use super::helpers::{StringHelpers};
 /// 
 /// ## Example
 /// ```
-/// use string_dist::dl;
+/// use string_distance::hamming_distance;
 /// 
-/// let str1:String = String.from("anthropology");
-/// let str2:String = String.from("anthropophagi");
-/// let dist:u8 = hamming::distance(&str1, &str2);
+/// let str1:String = String::from("anthropology");
+/// let str2:String = String::from("anthropophagi");
+/// let dist:usize = hamming_distance(&str1, &str2);
 /// println!("The Hamming Distance between {} and {} is {}", str1, str2, dist);
 /// ```
 /// 
@@ -27,18 +27,26 @@ use super::helpers::{StringHelpers};
 /// * -> The Hamming Distance of the passed 2 strings
 pub fn hamming_distance(str1:&str, str2:&str) -> usize
 {
-    let str_len_delta:usize = (str1.char_count()-str2.char_count()).abs() as usize;
+    hamming_distance_by_unit(str1, str2, Unit::Chars)
+}
 
-    let (shortest, longest) = if str1.char_count() < str2.char_count() {(str1, str2)} else {(str2, str1)};
+/// # Hamming Distance (by Unit)
+/// Identical to `hamming_distance`, but compares the strings in `unit`s, e.g. grapheme clusters instead of chars.
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `unit` - The unit the strings are compared in
+/// * -> The Hamming Distance of the passed 2 strings, in units
+pub fn hamming_distance_by_unit(str1:&str, str2:&str, unit:Unit) -> usize
+{
+    let (s1, s2) = unit.symbols(str1, str2);
+    let str_len_delta:usize = s1.len().abs_diff(s2.len());
 
-    let mut delta:usize = 0;
+    // zip stops at the end of the shorter string, the remaining units are counted by str_len_delta
+    let delta:usize = s1.iter().zip(s2.iter()).filter(|&(ch1, ch2)| ch1 != ch2).count();
 
-    for (i, ch1) in shortest.chars().enumerate()
-    {
-        delta += if ch1 != longest.nth_char(i as i32) {1} else {0};
-    }
-
-    (delta + str_len_delta)
+    delta + str_len_delta
 }
 
 #[cfg(test)]
@@ -56,4 +64,11 @@ mod tests {
         let d = hamming_distance("peter", "pedro");
         assert_eq!(d, 3);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn grapheme_distance() {
+        assert_eq!(hamming_distance("cafe\u{301}", "cafe"), 1);
+        assert_eq!(hamming_distance_by_unit("cafe\u{301}", "cafx", Unit::Graphemes), 1);
+        assert_eq!(hamming_distance_by_unit("café", "cafe", Unit::Bytes), 2);
+    }
+}
@@ -4,16 +4,12 @@ use std::cmp::{max};
 pub trait StringHelpers<'a>
 {
     fn char_count(&'a self) -> i32;
-    fn nth_char(&'a self, x:i32) -> char;
 }
 impl<'a> StringHelpers<'a> for &'a str
 {
     fn char_count(&'a self) -> i32 {
         self.chars().count() as i32
     }
-    fn nth_char(&'a self, x:i32) -> char {
-        self.chars().nth(x as usize).unwrap()
-    }
 }
 
 /// # Distance - To - Simularity
@@ -38,4 +34,24 @@ pub fn distance_to_simularity(str1:&str, str2:&str, dist:f64) -> f64
         return 1.0;
     }
     (1.0 - (dist / m)) as f64
-}
\ No newline at end of file
+}
+
+/// A small linear congruential generator for tests, so that generated strings are the same on every run.
+#[cfg(test)]
+pub struct Lcg(pub u64);
+
+#[cfg(test)]
+impl Lcg
+{
+    /// A pseudo-random number below `n`.
+    pub fn next(&mut self, n:usize) -> usize {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        ((self.0 >> 33) as usize) % n
+    }
+
+    /// A pseudo-random string of `len` chars from `alphabet`.
+    pub fn string(&mut self, len:usize, alphabet:&str) -> String {
+        let alphabet:Vec<char> = alphabet.chars().collect();
+        (0..len).map(|_| alphabet[self.next(alphabet.len())]).collect()
+    }
+}
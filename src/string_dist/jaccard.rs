@@ -0,0 +1,192 @@
+use super::token::{Tokenizer, Multiplicity};
+
+/// # Jaccard Simularity
+/// The tokens shared by both strings divided by the tokens in either, `|A ∩ B| / |A ∪ B|`.
+///
+/// See - https://en.wikipedia.org/wiki/Jaccard_index
+///
+/// ## Example
+/// ```
+/// use string_distance::{jaccard_simularity, QGramTokenizer, WhitespaceTokenizer, Multiplicity};
+///
+/// let bigrams = QGramTokenizer::new(2);
+/// assert_eq!(jaccard_simularity("night", "nacht", &bigrams, Multiplicity::Set), 1.0 / 7.0);
+/// assert_eq!(jaccard_simularity("a a b", "a b", &WhitespaceTokenizer, Multiplicity::Set), 1.0);
+/// assert_eq!(jaccard_simularity("a a b", "a b", &WhitespaceTokenizer, Multiplicity::Bag), 2.0 / 3.0);
+/// ```
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `tokenizer` - Splits the strings into tokens
+/// * `multiplicity` - Whether repeated tokens count once (`Set`) or every time (`Bag`)
+/// * -> The Jaccard Simularity, 0.0 <= simularity <= 1.0. Two strings without tokens are identical
+pub fn jaccard_simularity(str1:&str, str2:&str, tokenizer:&dyn Tokenizer, multiplicity:Multiplicity) -> f64
+{
+    let (a, b) = (tokenizer.bag(str1), tokenizer.bag(str2));
+    ratio(a.intersection_size(&b, multiplicity), a.union_size(&b, multiplicity))
+}
+
+/// # Sørensen-Dice Simularity
+/// Twice the tokens shared by both strings divided by the tokens of both, `2|A ∩ B| / (|A| + |B|)`.
+/// With bigrams, this is the Dice coefficient often used to compare short names.
+///
+/// See - https://en.wikipedia.org/wiki/S%C3%B8rensen%E2%80%93Dice_coefficient
+///
+/// ## Example
+/// ```
+/// use string_distance::{sorensen_dice_simularity, QGramTokenizer, Multiplicity};
+///
+/// let bigrams = QGramTokenizer::new(2);
+/// assert_eq!(sorensen_dice_simularity("night", "nacht", &bigrams, Multiplicity::Set), 0.25);
+/// ```
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `tokenizer` - Splits the strings into tokens
+/// * `multiplicity` - Whether repeated tokens count once (`Set`) or every time (`Bag`)
+/// * -> The Sørensen-Dice Simularity, 0.0 <= simularity <= 1.0. Two strings without tokens are identical
+pub fn sorensen_dice_simularity(str1:&str, str2:&str, tokenizer:&dyn Tokenizer, multiplicity:Multiplicity) -> f64
+{
+    let (a, b) = (tokenizer.bag(str1), tokenizer.bag(str2));
+    ratio(2 * a.intersection_size(&b, multiplicity), a.size(multiplicity) + b.size(multiplicity))
+}
+
+/// # Overlap Coefficient
+/// The tokens shared by both strings divided by the tokens of the string with fewer, `|A ∩ B| / min(|A|, |B|)`.
+/// A string whose tokens are all in the other string, such as an abbreviated name, scores 1.0.
+///
+/// See - https://en.wikipedia.org/wiki/Overlap_coefficient
+///
+/// ## Example
+/// ```
+/// use string_distance::{overlap_coefficient, WhitespaceTokenizer, Multiplicity};
+///
+/// let sim = overlap_coefficient("My Gym", "My Gym Children's Fitness Center", &WhitespaceTokenizer, Multiplicity::Set);
+/// assert_eq!(sim, 1.0);
+/// ```
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `tokenizer` - Splits the strings into tokens
+/// * `multiplicity` - Whether repeated tokens count once (`Set`) or every time (`Bag`)
+/// * -> The Overlap Coefficient, 0.0 <= simularity <= 1.0. Two strings without tokens are identical,
+///   and a string without tokens shares nothing with one that has tokens
+pub fn overlap_coefficient(str1:&str, str2:&str, tokenizer:&dyn Tokenizer, multiplicity:Multiplicity) -> f64
+{
+    let (a, b) = (tokenizer.bag(str1), tokenizer.bag(str2));
+    if a.is_empty() != b.is_empty() {
+        return 0.0;
+    }
+    ratio(a.intersection_size(&b, multiplicity), a.size(multiplicity).min(b.size(multiplicity)))
+}
+
+/// # Tversky Index
+/// An asymmetric generalization of Jaccard and Dice, `|A ∩ B| / (|A ∩ B| + α|A - B| + β|B - A|)`, which weighs
+/// the tokens only in `str1` by `alpha` and the tokens only in `str2` by `beta`. Comparing a variant (`str1`)
+/// to a prototype (`str2`) with a small `alpha` and large `beta` penalizes what the variant lacks.
+///
+/// * `alpha = beta = 1.0` is the Jaccard Simularity
+/// * `alpha = beta = 0.5` is the Sørensen-Dice Simularity
+///
+/// See - https://en.wikipedia.org/wiki/Tversky_index
+///
+/// ## Example
+/// ```
+/// use string_distance::{tversky_index, jaccard_simularity, WhitespaceTokenizer, Multiplicity};
+///
+/// let (str1, str2) = ("ABC Corp", "ABC Corp International");
+/// let t = tversky_index(str1, str2, &WhitespaceTokenizer, Multiplicity::Set, 1.0, 1.0);
+/// assert_eq!(t, jaccard_simularity(str1, str2, &WhitespaceTokenizer, Multiplicity::Set));
+/// assert_eq!(tversky_index(str1, str2, &WhitespaceTokenizer, Multiplicity::Set, 1.0, 0.5), 0.8);
+/// ```
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `tokenizer` - Splits the strings into tokens
+/// * `multiplicity` - Whether repeated tokens count once (`Set`) or every time (`Bag`)
+/// * `alpha` - The weight of the tokens only in `str1`, at least 0.0
+/// * `beta` - The weight of the tokens only in `str2`, at least 0.0
+/// * -> The Tversky Index, 0.0 <= simularity <= 1.0. Two strings without tokens are identical
+pub fn tversky_index(str1:&str, str2:&str, tokenizer:&dyn Tokenizer, multiplicity:Multiplicity, alpha:f64, beta:f64) -> f64
+{
+    assert!(alpha >= 0.0 && beta >= 0.0, "the Tversky weights must be at least 0.0");
+    let (a, b) = (tokenizer.bag(str1), tokenizer.bag(str2));
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let shared = a.intersection_size(&b, multiplicity) as f64;
+    let only_a = a.size(multiplicity) as f64 - shared;
+    let only_b = b.size(multiplicity) as f64 - shared;
+    let denominator = shared + alpha * only_a + beta * only_b;
+    if denominator == 0.0 { 0.0 } else { shared / denominator }
+}
+
+/// `shared / total`, where 0 tokens in total means both strings have no tokens and are identical.
+fn ratio(shared:usize, total:usize) -> f64
+{
+    if total == 0 { 1.0 } else { shared as f64 / total as f64 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::token::{QGramTokenizer, WhitespaceTokenizer};
+
+    static MULTIPLICITIES:[Multiplicity; 2] = [Multiplicity::Set, Multiplicity::Bag];
+
+    #[test]
+    fn empty_strings() {
+        for &m in MULTIPLICITIES.iter() {
+            let w = &WhitespaceTokenizer;
+            assert_eq!(jaccard_simularity("", " ", w, m), 1.0);
+            assert_eq!(sorensen_dice_simularity("", "", w, m), 1.0);
+            assert_eq!(overlap_coefficient("", "", w, m), 1.0);
+            assert_eq!(tversky_index("", "", w, m, 1.0, 1.0), 1.0);
+
+            assert_eq!(jaccard_simularity("a", "", w, m), 0.0);
+            assert_eq!(sorensen_dice_simularity("a", "", w, m), 0.0);
+            assert_eq!(overlap_coefficient("", "a", w, m), 0.0);
+            assert_eq!(tversky_index("a", "", w, m, 0.0, 0.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn bag_semantics() {
+        let w = &WhitespaceTokenizer;
+        assert_eq!(sorensen_dice_simularity("a a b", "a b", w, Multiplicity::Set), 1.0);
+        assert_eq!(sorensen_dice_simularity("a a b", "a b", w, Multiplicity::Bag), 0.8);
+        assert_eq!(overlap_coefficient("a a b", "a c", w, Multiplicity::Bag), 0.5);
+    }
+
+    #[test]
+    fn tversky_generalizes_jaccard_and_dice() {
+        let bigrams = QGramTokenizer::new(2).padded();
+        for &m in MULTIPLICITIES.iter() {
+            for &(str1, str2) in [("Jonathon", "Jonathan"), ("banana", "bandana"), ("paul", "pual")].iter() {
+                let jaccard = jaccard_simularity(str1, str2, &bigrams, m);
+                let dice = sorensen_dice_simularity(str1, str2, &bigrams, m);
+                assert!((tversky_index(str1, str2, &bigrams, m, 1.0, 1.0) - jaccard).abs() < 1e-12);
+                assert!((tversky_index(str1, str2, &bigrams, m, 0.5, 0.5) - dice).abs() < 1e-12);
+                assert!(dice >= jaccard);
+            }
+        }
+    }
+
+    #[test]
+    fn tversky_is_asymmetric() {
+        let w = &WhitespaceTokenizer;
+        let t = tversky_index("a b", "a b c d", w, Multiplicity::Set, 0.0, 1.0);
+        assert_eq!(t, 0.5);
+        assert_eq!(tversky_index("a b", "a b c d", w, Multiplicity::Set, 1.0, 0.0), 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_tversky_weight() {
+        tversky_index("a", "b", &WhitespaceTokenizer, Multiplicity::Set, -1.0, 1.0);
+    }
+}
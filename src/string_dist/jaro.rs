@@ -1,18 +1,164 @@
-use super::helpers::{StringHelpers};
 use std::cmp::min;
 use std::cmp::max;
 use std::str;
+use super::unit::Unit;
+use super::normalize::Normalizer;
 use std::f64;
+use std::fmt;
+use std::error::Error;
 
 /// The standard value for this constant is 0.1 in Winkler's work.
 static DEFAULT_SCALING_FACTOR:f64 = 0.1;
 
+/// Only Jaro simularities above this threshold are boosted by a common prefix, 0.7 in Winkler's work.
+static DEFAULT_BOOST_THRESHOLD:f64 = 0.7;
+
+/// The longest common prefix that is rewarded, 4 in Winkler's work.
+static DEFAULT_MAX_PREFIX_LENGTH:usize = 4;
+
+/// How much of a match an unmatched but similar character is worth in `strcmp95_simularity`, 3/10 in strcmp95.
+static SIMILAR_CHARACTER_WEIGHT:f64 = 0.3;
+
+/// The pairs of characters that are commonly confused by people or in transcription, from strcmp95.
+/// Either character of a pair is similar to the other.
+static SIMILAR_CHARACTERS:[(char, char); 36] = [
+    ('A', 'E'), ('A', 'I'), ('A', 'O'), ('A', 'U'), ('B', 'V'), ('E', 'I'), ('E', 'O'), ('E', 'U'),
+    ('I', 'O'), ('I', 'U'), ('O', 'U'), ('I', 'Y'), ('E', 'Y'), ('C', 'G'), ('E', 'F'), ('W', 'U'),
+    ('W', 'V'), ('X', 'K'), ('S', 'Z'), ('X', 'S'), ('Q', 'C'), ('U', 'V'), ('M', 'N'), ('L', 'I'),
+    ('Q', 'O'), ('P', 'R'), ('I', 'J'), ('2', 'Z'), ('5', 'S'), ('8', 'B'), ('1', 'I'), ('1', 'L'),
+    ('0', 'O'), ('0', 'Q'), ('C', 'K'), ('G', 'J')
+];
+
+/// # Jaro Winkler Options
+/// The parameters of `jaro_simularity` and `jaro_winkler_simularity`, built with `JWOptions::builder()`.
+/// `JWOptions::default()` (the same as passing `None`) uses Winkler's parameters, and is case insensitive.
+///
+/// ## Example
+/// ```
+/// use string_distance::{JWOptions, jaro_winkler_simularity};
+///
+/// let opts = JWOptions::builder().scaling_factor(0.2).max_prefix_length(3).build().unwrap();
+/// let default = jaro_winkler_simularity("dwayne", "duane", None);
+/// assert!(jaro_winkler_simularity("dwayne", "duane", Some(opts)) > default);
+///
+/// assert!(JWOptions::builder().scaling_factor(0.3).build().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct JWOptions 
 {
-    scaling_factor:Option<f64>,
-    case_sensitive:bool
+    scaling_factor:f64,
+    case_sensitive:bool,
+    boost_threshold:f64,
+    max_prefix_length:usize
+}
+
+impl JWOptions
+{
+    pub fn builder() -> JWOptionsBuilder {
+        JWOptionsBuilder { opts: JWOptions::default() }
+    }
+
+    /// How much each character of the common prefix raises the score, `p` in Winkler's work.
+    pub fn scaling_factor(&self) -> f64 { self.scaling_factor }
+
+    /// Whether `a` and `A` are different characters.
+    pub fn case_sensitive(&self) -> bool { self.case_sensitive }
+
+    /// Only Jaro simularities above the threshold are raised by a common prefix.
+    pub fn boost_threshold(&self) -> f64 { self.boost_threshold }
+
+    /// The longest common prefix that raises the score, `l` is at most this.
+    pub fn max_prefix_length(&self) -> usize { self.max_prefix_length }
+}
+
+impl Default for JWOptions
+{
+    fn default() -> JWOptions {
+        JWOptions {
+            scaling_factor: DEFAULT_SCALING_FACTOR,
+            case_sensitive: false,
+            boost_threshold: DEFAULT_BOOST_THRESHOLD,
+            max_prefix_length: DEFAULT_MAX_PREFIX_LENGTH
+        }
+    }
+}
+
+/// Builds a validated `JWOptions`, every parameter not set keeps its default.
+#[derive(Clone, Copy, Debug)]
+pub struct JWOptionsBuilder
+{
+    opts:JWOptions
+}
+
+impl JWOptionsBuilder
+{
+    pub fn scaling_factor(mut self, scaling_factor:f64) -> JWOptionsBuilder {
+        self.opts.scaling_factor = scaling_factor;
+        self
+    }
+
+    pub fn case_sensitive(mut self, case_sensitive:bool) -> JWOptionsBuilder {
+        self.opts.case_sensitive = case_sensitive;
+        self
+    }
+
+    pub fn boost_threshold(mut self, boost_threshold:f64) -> JWOptionsBuilder {
+        self.opts.boost_threshold = boost_threshold;
+        self
+    }
+
+    pub fn max_prefix_length(mut self, max_prefix_length:usize) -> JWOptionsBuilder {
+        self.opts.max_prefix_length = max_prefix_length;
+        self
+    }
+
+    /// Validates the options. The boosted score is `jaro + l * p * (1 - jaro)`, which stays within 1.0
+    /// only while `p * max_prefix_length <= 1.0`.
+    pub fn build(self) -> Result<JWOptions, JWOptionsError> {
+        let o = self.opts;
+        if !o.scaling_factor.is_finite() {
+            return Err(JWOptionsError::NonFiniteScalingFactor(o.scaling_factor));
+        }
+        if !(o.scaling_factor >= 0.0 && o.scaling_factor * o.max_prefix_length as f64 <= 1.0) {
+            return Err(JWOptionsError::ScalingFactor(o.scaling_factor, o.max_prefix_length));
+        }
+        if !(0.0..=1.0).contains(&o.boost_threshold) {
+            return Err(JWOptionsError::BoostThreshold(o.boost_threshold));
+        }
+        Ok(o)
+    }
+}
+
+/// The reason a `JWOptionsBuilder` rejected its options.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JWOptionsError
+{
+    /// The scaling factor is negative, or large enough to push scores above 1.0 with the max prefix length.
+    ScalingFactor(f64, usize),
+    /// The scaling factor is infinite or NaN.
+    NonFiniteScalingFactor(f64),
+    /// The boost threshold is not within 0.0 to 1.0.
+    BoostThreshold(f64)
 }
 
+impl fmt::Display for JWOptionsError
+{
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JWOptionsError::ScalingFactor(p, 0) =>
+                write!(f, "scaling factor {} must be at least 0.0", p),
+            JWOptionsError::ScalingFactor(p, l) =>
+                write!(f, "scaling factor {} must be between 0.0 and {} for a max prefix length of {}", p, 1.0 / l as f64, l),
+            JWOptionsError::NonFiniteScalingFactor(p) =>
+                write!(f, "scaling factor {} must be a finite number", p),
+            JWOptionsError::BoostThreshold(t) =>
+                write!(f, "boost threshold {} must be between 0.0 and 1.0", t)
+        }
+    }
+}
+
+impl Error for JWOptionsError {}
+
 /// The Jaro Simularity - calculates the string distance. Often referred as a distance metric, 
 /// 
 /// See - https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance
@@ -21,9 +167,11 @@ pub struct JWOptions
 /// # Example
 /// 
 /// ```
-/// let str1:String = String.from("anthropology");
-/// let str2:String = String.from("anthropophagi");
-/// let dist:f64 = jaro_simularity&str1, &str2);
+/// use string_distance::jaro_simularity;
+/// 
+/// let str1:String = String::from("anthropology");
+/// let str2:String = String::from("anthropophagi");
+/// let dist:f64 = jaro_simularity(&str1, &str2, None);
 /// println!("The Jaro Distance between {} and {} is {}", str1, str2, dist);
 /// ```
 /// 
@@ -34,74 +182,97 @@ pub struct JWOptions
 /// -> f64 The Jaro Winkler Distance of the passed 2 strings. A double value between 0 and 1. 0 meaning no simularity, and 1 being identical.
 pub fn jaro_simularity(str1:&str, str2:&str, opts:Option<JWOptions>) -> f64
 {
-    let (len1, len2) = (str1.char_count() as f64, str2.char_count() as f64);
-
-    // Leave early as at least 1 string is empty
-    if len1 == 0f64 && len2 == 0f64 { return 1f64; }
-    if len1 == 0f64 || len2 == 0f64 { return 0f64; }
+    jaro_simularity_by_unit(str1, str2, opts, Unit::Chars)
+}
 
-    // Use lowercase if case insensitive
-    let lcs1 = str1.to_string().to_lowercase();
-    let lcs2 = str2.to_string().to_lowercase();
+/// The Jaro Simularity, comparing the strings in `unit`s (e.g. grapheme clusters) instead of chars.
+/// See `jaro_simularity`.
+///
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `opts` - Options to pass to vary the calculation weights.
+/// * `unit` - The unit the strings are compared in
+///
+/// -> f64 The Jaro Simularity of the passed 2 strings, between 0 and 1.
+pub fn jaro_simularity_by_unit(str1:&str, str2:&str, opts:Option<JWOptions>, unit:Unit) -> f64
+{
+    let (s1, s2) = symbols(str1, str2, &opts, unit);
+    jaro_symbols(&s1, &s2)
+}
 
-    let is_case_sens = |o:&Option<JWOptions>| {
-        if let Some( JWOptions {case_sensitive: cs, ..} ) = *o {
-            return cs;
-        } 
-        return false;
-    };
+/// Splits the strings into unit symbols, case folded first unless the options are case sensitive.
+fn symbols(str1:&str, str2:&str, opts:&Option<JWOptions>, unit:Unit) -> (Vec<char>, Vec<char>)
+{
+    if opts.unwrap_or_default().case_sensitive {
+        unit.symbols(str1, str2)
+    } else {
+        let folding = Normalizer::new().case_fold();
+        unit.symbols(&folding.normalize(str1), &folding.normalize(str2))
+    }
+}
 
-    let (s1,s2) = if is_case_sens(&opts) { (str1, str2) } else { (lcs1.as_str(), lcs2.as_str()) };
-    
-    // Leave early if strings are identical
-    if s1 == s2 { return 1f64; }
+/// The characters matched by the Jaro algorithm, and the number of them that are out of order.
+struct JaroMatches
+{
+    s1_matches:Vec<bool>,
+    s2_matches:Vec<bool>,
+    m:usize,
+    unordered:usize
+}
 
-    let match_dist = ((f64::max(len1, len2)/2.0).floor() - 1.0) as i32;
-    let mut s1_matches:Vec<bool> = vec![false; len1 as usize]; 
-    let mut s2_matches:Vec<bool> = vec![false; len2 as usize]; 
-    let mut m:f64 = 0.0;
+/// Matches every char of `s1` to the first equal, unmatched char of `s2` within the match distance,
+/// then counts the matched chars that differ when both strings' matches are read in order.
+fn jaro_matches(s1:&[char], s2:&[char]) -> JaroMatches
+{
+    let match_dist = (max(s1.len(), s2.len()) / 2).saturating_sub(1);
+    let mut s1_matches:Vec<bool> = vec![false; s1.len()];
+    let mut s2_matches:Vec<bool> = vec![false; s2.len()];
+    let mut m = 0;
 
-    for i in 0..len1 as i32
+    for (i, ch1) in s1.iter().enumerate()
     {
-        let low  = max(i-match_dist, 0);
-        let high = min(i+match_dist,len2 as i32 - 1);
+        let low  = i.saturating_sub(match_dist);
+        let high = min(i + match_dist + 1, s2.len());
 
-        for j in low..high+1
+        for j in low..high
         {
-            if s2_matches[j as usize] == false && s1.nth_char(i) == s2.nth_char(j) {
-                m += 1.0;
-                s1_matches[i as usize] = true;
-                s2_matches[j as usize] = true;
+            if !s2_matches[j] && *ch1 == s2[j] {
+                m += 1;
+                s1_matches[i] = true;
+                s2_matches[j] = true;
                 break;
             }
         }
     }
 
-    // Leave early if no matches found
-    if m == 0.0 { return 0f64; }
+    let matched1 = s1.iter().zip(s1_matches.iter()).filter(|&(_, &matched)| matched);
+    let matched2 = s2.iter().zip(s2_matches.iter()).filter(|&(_, &matched)| matched);
+    let unordered = matched1.zip(matched2).filter(|&((ch1, _), (ch2, _))| ch1 != ch2).count();
 
-    // Count the transpositions.
-    let mut k = 0;
-    let mut num_trans:f64 = 0.0;
+    JaroMatches { s1_matches, s2_matches, m, unordered }
+}
 
-    for i in 0..len1 as i32
-    {
-        if s1_matches[i as usize] { 
+fn jaro_symbols(s1:&[char], s2:&[char]) -> f64
+{
+    // Leave early as at least 1 string is empty
+    if s1.is_empty() && s2.is_empty() { return 1f64; }
+    if s1.is_empty() || s2.is_empty() { return 0f64; }
 
-            let mut j = k;
-            while s2_matches[j as usize] == false { k = j + 1; j += 1; }
+    // Leave early if strings are identical
+    if s1 == s2 { return 1f64; }
 
+    let matches = jaro_matches(s1, s2);
 
-            if s1_matches[i as usize] != s2_matches[j as usize] {
-                num_trans += 0.5;
-            }
-        }     
-    }
+    // Leave early if no matches found
+    if matches.m == 0 { return 0f64; }
 
-    // The Jaro distance
-    let weight = (m / len1  + m / len2 + (m - num_trans) / m) / 3.0;
+    // Every transposition puts 2 matched chars out of order
+    let (len1, len2) = (s1.len() as f64, s2.len() as f64);
+    let m = matches.m as f64;
+    let num_trans = matches.unordered as f64 / 2.0;
 
-    weight
+    // The Jaro distance
+    (m / len1  + m / len2 + (m - num_trans) / m) / 3.0
 }
 
 /// The Jaro Winkler Simularity - calculates the string distance 
@@ -116,9 +287,11 @@ pub fn jaro_simularity(str1:&str, str2:&str, opts:Option<JWOptions>) -> f64
 /// # Example
 /// 
 /// ```
-/// let str1:String = String.from("anthropology");
-/// let str2:String = String.from("anthropophagi");
-/// let dist:f64 = jaro_winkler_simularity(&str1, &str2);
+/// use string_distance::jaro_winkler_simularity;
+/// 
+/// let str1:String = String::from("anthropology");
+/// let str2:String = String::from("anthropophagi");
+/// let dist:f64 = jaro_winkler_simularity(&str1, &str2, None);
 /// println!("The Jaro Wrinkler Distance between {} and {} is {}", str1, str2, dist);
 /// ```
 /// 
@@ -129,29 +302,133 @@ pub fn jaro_simularity(str1:&str, str2:&str, opts:Option<JWOptions>) -> f64
 /// -> f64 The Jaro Winkler Distance of the passed 2 strings. A double value between 0 and 1. 0 meaning no simularity, and 1 being identical.
 pub fn jaro_winkler_simularity(str1: &str, str2: &str, opts:Option<JWOptions>) -> f64
 {
+    jaro_winkler_simularity_by_unit(str1, str2, opts, Unit::Chars)
+}
+
+/// The Jaro Winkler Simularity, comparing the strings in `unit`s (e.g. grapheme clusters) instead of chars.
+/// The common prefix is also measured in units. See `jaro_winkler_simularity`.
+///
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `opts` - Options to pass to vary the calculation weights.
+/// * `unit` - The unit the strings are compared in
+///
+/// -> f64 The Jaro Winkler Simularity of the passed 2 strings, between 0 and 1.
+pub fn jaro_winkler_simularity_by_unit(str1:&str, str2:&str, opts:Option<JWOptions>, unit:Unit) -> f64
+{
+    let (s1, s2) = symbols(str1, str2, &opts, unit);
 
     // Jaro Winkler weight, JW gives more favourable ratings to matches at the beginning of the string
-    let p:f64 = if let Some(JWOptions{scaling_factor:Some(sf),..}) = opts {(sf)} else {DEFAULT_SCALING_FACTOR};
-    let weight = jaro_simularity(str1, str2, opts);
+    let opts = opts.unwrap_or_default();
+    let p:f64 = opts.scaling_factor;
+    let weight = jaro_symbols(&s1, &s2);
     let mut jw_weight = weight;
-    let mut l = 0;
 
     if weight == 0.0 || weight == 1.0 {
         return weight;
     }
 
-    let get_char = |s:&str, i:usize|{s.chars().nth(i).unwrap()};
-    if weight > 0.7 {
-      
-      while get_char(str1,l) == get_char(str2,l) && l < 4
-      {
-        l += 1;
-      }
+    if weight > opts.boost_threshold {
+
+      let l = s1.iter().zip(s2.iter()).take(opts.max_prefix_length).take_while(|&(ch1, ch2)| ch1 == ch2).count();
 
       jw_weight = weight + ((l as f64) * p * (1.0 - weight));
     }
 
-    return jw_weight;
+    jw_weight
+}
+
+/// # strcmp95 Simularity
+/// The Jaro Winkler Simularity with the adjustments of the U.S. Census Bureau's `strcmp95` comparator,
+/// by Winkler, McLaughlin and Lynch:
+/// 1. Leading and trailing spaces are ignored
+/// 2. Unmatched characters that are commonly confused, such as `I`/`L` or `O`/`Q`, count as 0.3 of a match
+///    (McLaughlin)
+/// 3. The common prefix that raises the score stops at the first digit
+/// 4. With `long_strings`, strings of more than 4 characters whose matches (beyond the prefix) are more than
+///    half of the remaining characters are raised further (Lynch)
+///
+/// See - https://www.census.gov/content/dam/Census/library/working-papers/1990/adrm/rr90-11.pdf
+/// And the strcmp95.c reference implementation.
+///
+/// Transpositions are counted with integer division as in `strcmp95.c`, so scores can differ slightly from
+/// `jaro_winkler_simularity` even when no adjustment applies.
+///
+/// ## Example
+/// ```
+/// use string_distance::{jaro_winkler_simularity, strcmp95_simularity};
+///
+/// let jw = jaro_winkler_simularity("jon", "jan", None);
+/// assert!(strcmp95_simularity("jon", "jan", None, true) > jw);
+/// assert!(strcmp95_simularity("nichleson", "nichulson", None, true) > strcmp95_simularity("nichleson", "nichulson", None, false));
+/// ```
+///
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `opts` - Options to pass to vary the calculation weights, and whether the comparison is case sensitive.
+///   The similar characters are matched case insensitively either way.
+/// * `long_strings` - Whether to apply the long string adjustment
+///
+/// -> f64 The strcmp95 Simularity of the passed 2 strings, between 0 and 1.
+pub fn strcmp95_simularity(str1:&str, str2:&str, opts:Option<JWOptions>, long_strings:bool) -> f64
+{
+    let (s1, s2) = symbols(str1.trim_matches(' '), str2.trim_matches(' '), &opts, Unit::Chars);
+    let opts = opts.unwrap_or_default();
+
+    // Leave early as at least 1 string is empty
+    if s1.is_empty() && s2.is_empty() { return 1f64; }
+    if s1.is_empty() || s2.is_empty() { return 0f64; }
+
+    let matches = jaro_matches(&s1, &s2);
+
+    // Leave early if no matches found
+    if matches.m == 0 { return 0f64; }
+
+    // Give partial credit to the unmatched characters of s1 that are similar to an unmatched character of s2
+    let shortest = min(s1.len(), s2.len());
+    let mut similar = 0;
+    if shortest > matches.m {
+        let mut s2_used = matches.s2_matches.clone();
+        let unmatched1 = s1.iter().zip(matches.s1_matches.iter()).filter(|&(_, &matched)| !matched);
+        for (ch1, _) in unmatched1
+        {
+            if let Some(j) = (0..s2.len()).find(|&j| !s2_used[j] && is_similar(*ch1, s2[j])) {
+                similar += 1;
+                s2_used[j] = true;
+            }
+        }
+    }
+
+    let (len1, len2) = (s1.len() as f64, s2.len() as f64);
+    let m = matches.m as f64;
+    let m_similar = m + similar as f64 * SIMILAR_CHARACTER_WEIGHT;
+    let num_trans = (matches.unordered / 2) as f64;
+    let mut weight = (m_similar / len1 + m_similar / len2 + (m - num_trans) / m) / 3.0;
+
+    if weight > opts.boost_threshold {
+
+        // Winkler's common prefix, which may not include digits
+        let l = s1.iter().zip(s2.iter())
+            .take(opts.max_prefix_length)
+            .take_while(|&(ch1, ch2)| ch1 == ch2 && !ch1.is_ascii_digit())
+            .count();
+        weight += (l as f64) * opts.scaling_factor * (1.0 - weight);
+
+        // Lynch's long string adjustment, at least 2 more characters must match after the prefix
+        // and the matches must be more than half of the remaining characters
+        if long_strings && shortest > 4 && matches.m > l + 1 && 2 * matches.m >= shortest + l && !s1[0].is_ascii_digit() {
+            weight += (1.0 - weight) * (matches.m - l - 1) as f64 / (len1 + len2 - 2.0 * l as f64 + 2.0);
+        }
+    }
+
+    weight
+}
+
+/// Whether strcmp95 gives partial credit for `ch1` unmatched against `ch2`.
+fn is_similar(ch1:char, ch2:char) -> bool
+{
+    let (ch1, ch2) = (ch1.to_ascii_uppercase(), ch2.to_ascii_uppercase());
+    SIMILAR_CHARACTERS.iter().any(|&(a, b)| (a, b) == (ch1, ch2) || (b, a) == (ch1, ch2))
 }
 
 /// Jaro Winkler Distance Tests. 
@@ -187,7 +464,7 @@ mod tests {
     fn frog_fog_distance() {
         let d = jaro_winkler_simularity("frog", "fog", None);
         let expected_d = 0.93f64;
-        assert!(cmp_approx(d, expected_d), format!("Expected value {} and actual value {} were too far apart", expected_d, d));
+        assert!(cmp_approx(d, expected_d), "Expected value {} and actual value {} were too far apart", expected_d, d);
     }
 
     #[test]
@@ -200,48 +477,164 @@ mod tests {
     fn elephant_hippo_distance() {
         let d = jaro_winkler_simularity("elephant", "hippo", None);
         let expected_d = 0.44f64;
-        assert!(cmp_approx(d, expected_d), format!("Expected value {} and actual value {} were too far apart", expected_d, d));
+        assert!(cmp_approx(d, expected_d), "Expected value {} and actual value {} were too far apart", expected_d, d);
     }
 
     #[test]
     fn hippo_elephant_distance() {
         let d = jaro_winkler_simularity("hippo", "elephant", None);
         let expected_d = 0.44f64;
-        assert!(cmp_approx(d, expected_d), format!("Expected value {} and actual value {} were too far apart", expected_d, d));
+        assert!(cmp_approx(d, expected_d), "Expected value {} and actual value {} were too far apart", expected_d, d);
     }
 
     #[test]
     fn hello_hallo_distance() {
         let d = jaro_winkler_simularity("hello", "hallo", None);
         let expected_d = 0.88f64;
-        assert!(cmp_approx(d, expected_d), format!("Expected value {} and actual value {} were too far apart", expected_d, d));
+        assert!(cmp_approx(d, expected_d), "Expected value {} and actual value {} were too far apart", expected_d, d);
     }
 
     #[test]
     fn abc_corp_distance() {
         let d = jaro_winkler_simularity("ABC Corporation", "ABC Corp", None);
         let expected_d = 0.93f64;
-        assert!(cmp_approx(d, expected_d), format!("Expected value {} and actual value {} were too far apart", expected_d, d));
+        assert!(cmp_approx(d, expected_d), "Expected value {} and actual value {} were too far apart", expected_d, d);
     }
 
     #[test]
     fn ampersand_comparison() {
         let d = jaro_winkler_simularity("D N H Enterprises Inc", "D & H Enterprises, Inc.", None);
         let expected_d = 0.95f64;
-        assert!(cmp_approx(d, expected_d), format!("Expected value {} and actual value {} were too far apart", expected_d, d));
+        assert!(cmp_approx(d, expected_d), "Expected value {} and actual value {} were too far apart", expected_d, d);
     }
 
     #[test]
     fn name_abbreviation_comparison() {
         let d = jaro_winkler_simularity("My Gym Children's Fitness Center", "My Gym. Childrens Fitness", None);
         let expected_d = 0.92f64;
-        assert!(cmp_approx(d, expected_d), format!("Expected value {} and actual value {} were too far apart", expected_d, d));
+        assert!(cmp_approx(d, expected_d), "Expected value {} and actual value {} were too far apart", expected_d, d);
     }
 
     #[test]
     fn typo_comaprison() {
         let d = jaro_winkler_simularity("PENNSYLVANIA", "PENNCISYLVNIA", None);
         let expected_d = 0.92f64;
-        assert!(cmp_approx(d, expected_d), format!("Expected value {} and actual value {} were too far apart", expected_d, d));
+        assert!(cmp_approx(d, expected_d), "Expected value {} and actual value {} were too far apart", expected_d, d);
+    }
+
+    #[test]
+    fn short_common_prefix_comparison() {
+        let d = jaro_winkler_simularity("ab", "abc", None);
+        let expected_d = 0.91f64;
+        assert!(cmp_approx(d, expected_d), "Expected value {} and actual value {} were too far apart", expected_d, d);
+    }
+
+    #[test]
+    fn grapheme_comparison() {
+        let (composed, decomposed) = ("Ren\u{e9}e", "Rene\u{301}e");
+        assert!(jaro_simularity(composed, decomposed, None) < 1.0);
+        assert_eq!(jaro_simularity_by_unit("Rene\u{301}e", decomposed, None, Unit::Graphemes), 1.0);
+        let d = jaro_winkler_simularity_by_unit("Rene\u{301}e", "Rene\u{301}", None, Unit::Graphemes);
+        let expected_d = 0.96f64;
+        assert!(cmp_approx(d, expected_d), "Expected value {} and actual value {} were too far apart", expected_d, d);
+    }
+
+    #[test]
+    fn word_comparison() {
+        let d = jaro_simularity_by_unit("John Paul Smith Jr.", "john smith jr", None, Unit::Words);
+        let expected_d = 0.92f64;
+        assert!(cmp_approx(d, expected_d), "Expected value {} and actual value {} were too far apart", expected_d, d);
+        assert_eq!(jaro_simularity_by_unit("John  Smith", "john smith.", None, Unit::Words), 1.0);
+    }
+
+    #[test]
+    fn default_options_equal_none() {
+        let opts = JWOptions::builder().build().unwrap();
+        assert_eq!(opts, JWOptions::default());
+        assert_eq!(jaro_winkler_simularity("dixon", "dicksonx", Some(opts)), jaro_winkler_simularity("dixon", "dicksonx", None));
+    }
+
+    #[test]
+    fn case_sensitive_option() {
+        let opts = JWOptions::builder().case_sensitive(true).build().unwrap();
+        assert_eq!(jaro_simularity("PAUL", "paul", None), 1.0);
+        assert_eq!(jaro_simularity("PAUL", "paul", Some(opts)), 0.0);
+    }
+
+    #[test]
+    fn boost_threshold_and_prefix_options() {
+        let jaro = jaro_simularity("dwayne", "duane", None);
+        let never = JWOptions::builder().boost_threshold(1.0).build().unwrap();
+        assert_eq!(jaro_winkler_simularity("dwayne", "duane", Some(never)), jaro);
+
+        let no_prefix = JWOptions::builder().max_prefix_length(0).build().unwrap();
+        assert_eq!(jaro_winkler_simularity("martha", "marhta", Some(no_prefix)), jaro_simularity("martha", "marhta", None));
+    }
+
+    #[test]
+    fn invalid_options() {
+        assert_eq!(JWOptions::builder().scaling_factor(0.26).build(), Err(JWOptionsError::ScalingFactor(0.26, 4)));
+        assert_eq!(JWOptions::builder().scaling_factor(-0.1).build(), Err(JWOptionsError::ScalingFactor(-0.1, 4)));
+        assert!(JWOptions::builder().scaling_factor(0.5).max_prefix_length(2).build().is_ok());
+        assert_eq!(JWOptions::builder().boost_threshold(1.5).build(), Err(JWOptionsError::BoostThreshold(1.5)));
+        assert_eq!(JWOptionsError::BoostThreshold(1.5).to_string(), "boost threshold 1.5 must be between 0.0 and 1.0");
+        assert_eq!(JWOptions::builder().scaling_factor(-0.1).max_prefix_length(0).build(), Err(JWOptionsError::ScalingFactor(-0.1, 0)));
+        assert_eq!(JWOptionsError::ScalingFactor(-0.1, 0).to_string(), "scaling factor -0.1 must be at least 0.0");
+        let inf = JWOptions::builder().scaling_factor(f64::INFINITY).max_prefix_length(0).build();
+        assert_eq!(inf, Err(JWOptionsError::NonFiniteScalingFactor(f64::INFINITY)));
+        assert_eq!(inf.unwrap_err().to_string(), "scaling factor inf must be a finite number");
+        assert!(JWOptions::builder().scaling_factor(f64::NAN).build().is_err());
+    }
+
+    #[test]
+    fn transpositions_are_counted() {
+        assert!((jaro_simularity("MARTHA", "MARHTA", None) - 0.944).abs() < 0.001);
+    }
+
+    /// The Jaro, Winkler, McLaughlin (similar characters) and Lynch (long strings) columns of Table 6 in
+    /// Winkler's "String Comparator Metrics and Enhanced Decision Rules in the Fellegi-Sunter Model of Record Linkage".
+    static STRCMP95_VECTORS:[(&str, &str, f64, f64, f64, f64); 11] = [
+        ("SHACKLEFORD", "SHACKELFORD", 0.970, 0.982, 0.982, 0.989),
+        ("DUNNINGHAM", "CUNNIGHAM", 0.896, 0.896, 0.896, 0.931),
+        ("NICHLESON", "NICHULSON", 0.926, 0.956, 0.969, 0.977),
+        ("JONES", "JOHNSON", 0.790, 0.832, 0.860, 0.874),
+        ("MASSEY", "MASSIE", 0.889, 0.933, 0.953, 0.953),
+        ("ABROMS", "ABRAMS", 0.889, 0.922, 0.946, 0.952),
+        ("MARHTA", "MARTHA", 0.944, 0.961, 0.961, 0.971),
+        ("MICHELLE", "MICHAEL", 0.869, 0.921, 0.938, 0.944),
+        ("JULIES", "JULIUS", 0.889, 0.933, 0.953, 0.953),
+        ("SEAN", "SUSAN", 0.783, 0.805, 0.845, 0.845),
+        ("JON", "JOHN", 0.917, 0.933, 0.933, 0.933)
+    ];
+
+    #[test]
+    fn strcmp95_reference_vectors() {
+        let close = |actual:f64, expected:f64| (actual - expected).abs() < 0.001;
+        for &(str1, str2, jaro, winkler, mclaughlin, lynch) in STRCMP95_VECTORS.iter() {
+            assert!(close(jaro_simularity(str1, str2, None), jaro), "jaro {} {}", str1, str2);
+            assert!(close(jaro_winkler_simularity(str1, str2, None), winkler), "winkler {} {}", str1, str2);
+            assert!(close(strcmp95_simularity(str1, str2, None, false), mclaughlin), "mclaughlin {} {}", str1, str2);
+            assert!(close(strcmp95_simularity(str1, str2, None, true), lynch), "lynch {} {}", str1, str2);
+        }
+    }
+
+    #[test]
+    fn strcmp95_similar_characters() {
+        assert!((jaro_winkler_simularity("JON", "JAN", None) - 0.8).abs() < 0.001);
+        assert!((strcmp95_simularity("JON", "JAN", None, true) - 0.86).abs() < 0.001);
+        assert!(strcmp95_simularity("lopez", "iopez", None, false) > strcmp95_simularity("lopez", "xopez", None, false));
+        assert_eq!(strcmp95_simularity("abc", "xyz", None, true), 0.0);
+    }
+
+    #[test]
+    fn strcmp95_spaces_and_digits() {
+        assert_eq!(strcmp95_simularity("  SMITH ", "SMITH", None, true), 1.0);
+        assert_eq!(strcmp95_simularity("", "", None, true), 1.0);
+        assert_eq!(strcmp95_simularity("SMITH", "   ", None, true), 0.0);
+
+        // A digit ends the common prefix
+        let jaro = jaro_simularity("12345a", "12345b", None);
+        assert!(strcmp95_simularity("12345a", "12345b", None, false) - jaro < 1e-9);
+        assert!(jaro_winkler_simularity("12345a", "12345b", None) > jaro);
     }
 }
@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+use super::levenshtein::CostModel;
+
+/// # Keyboard Layout
+/// The physical position of every key on a keyboard, used to measure how far apart 2 characters are typed.
+/// Positions are measured in key widths, with the stagger of each row taken into account, so that
+/// horizontally adjacent keys are 1.0 apart and diagonally adjacent keys are slightly further.
+/// Shifted characters share the position of their unshifted key.
+///
+/// ## Example
+/// ```
+/// use string_distance::KeyboardLayout;
+///
+/// let qwerty = KeyboardLayout::qwerty();
+/// assert_eq!(qwerty.key_distance('q', 'w'), Some(1.0));
+/// assert_eq!(qwerty.key_distance('a', 'A'), Some(0.0));
+/// assert!(qwerty.key_distance('a', 'p') > qwerty.key_distance('a', 's'));
+/// ```
+#[derive(Clone, Debug)]
+pub struct KeyboardLayout
+{
+    keys:HashMap<char, (f64, f64)>
+}
+
+/// Row stagger, in key widths, of an ANSI keyboard (Tab 1.5, Caps Lock 1.75, Left Shift 2.25).
+static ANSI_OFFSETS:[f64; 4] = [0.0, 1.5, 1.75, 2.25];
+
+/// Row stagger, in key widths, of an ISO keyboard. The short Left Shift is followed by an extra key.
+static ISO_OFFSETS:[f64; 4] = [0.0, 1.5, 1.75, 1.25];
+
+impl KeyboardLayout
+{
+    /// Builds a layout from its rows, top to bottom.
+    ///
+    /// * `rows` - For each row the unshifted characters, the shifted characters of the same keys, and the
+    ///   horizontal offset of the row's first key. A space in a row marks a key with no character.
+    pub fn from_rows(rows:&[(&str, &str, f64)]) -> KeyboardLayout
+    {
+        let mut keys = HashMap::new();
+        for (y, &(unshifted, shifted, offset)) in rows.iter().enumerate()
+        {
+            for row in [unshifted, shifted].iter()
+            {
+                for (x, ch) in row.chars().enumerate()
+                {
+                    if ch != ' ' {
+                        keys.entry(ch).or_insert((x as f64 + offset, y as f64));
+                    }
+                }
+            }
+        }
+        KeyboardLayout { keys }
+    }
+
+    fn with_offsets(rows:[(&str, &str); 4], offsets:&[f64; 4]) -> KeyboardLayout {
+        let rows:Vec<(&str, &str, f64)> = rows.iter()
+            .zip(offsets.iter())
+            .map(|(&(unshifted, shifted), &offset)| (unshifted, shifted, offset))
+            .collect();
+        KeyboardLayout::from_rows(&rows)
+    }
+
+    /// The US QWERTY layout.
+    pub fn qwerty() -> KeyboardLayout {
+        KeyboardLayout::with_offsets([
+            ("`1234567890-=", "~!@#$%^&*()_+"),
+            ("qwertyuiop[]\\", "QWERTYUIOP{}|"),
+            ("asdfghjkl;'", "ASDFGHJKL:\""),
+            ("zxcvbnm,./", "ZXCVBNM<>?")
+        ], &ANSI_OFFSETS)
+    }
+
+    /// The German QWERTZ layout.
+    pub fn qwertz() -> KeyboardLayout {
+        KeyboardLayout::with_offsets([
+            ("^1234567890ß´", "°!\"§$%&/()=?`"),
+            ("qwertzuiopü+", "QWERTZUIOPÜ*"),
+            ("asdfghjklöä#", "ASDFGHJKLÖÄ'"),
+            ("<yxcvbnm,.-", ">YXCVBNM;:_")
+        ], &ISO_OFFSETS)
+    }
+
+    /// The French AZERTY layout.
+    pub fn azerty() -> KeyboardLayout {
+        KeyboardLayout::with_offsets([
+            ("²&é\"'(-è_çà)=", " 1234567890°+"),
+            ("azertyuiop^$", "AZERTYUIOP¨£"),
+            ("qsdfghjklmù*", "QSDFGHJKLM%µ"),
+            ("<wxcvbn,;:!", ">WXCVBN?./§")
+        ], &ISO_OFFSETS)
+    }
+
+    /// The US Dvorak layout.
+    pub fn dvorak() -> KeyboardLayout {
+        KeyboardLayout::with_offsets([
+            ("`1234567890[]", "~!@#$%^&*(){}"),
+            ("',.pyfgcrl/=\\", "\"<>PYFGCRL?+|"),
+            ("aoeuidhtns-", "AOEUIDHTNS_"),
+            (";qjkxbmwvz", ":QJKXBMWVZ")
+        ], &ANSI_OFFSETS)
+    }
+
+    /// The position of the key that types `ch`, if it is on this layout.
+    pub fn position(&self, ch:char) -> Option<(f64, f64)> {
+        self.keys.get(&ch).cloned()
+    }
+
+    /// The straight line distance, in key widths, between the keys that type `ch1` and `ch2`.
+    /// Returns `None` if either character is not on this layout.
+    pub fn key_distance(&self, ch1:char, ch2:char) -> Option<f64> {
+        match (self.position(ch1), self.position(ch2)) {
+            (Some((x1, y1)), Some((x2, y2))) => Some(((x1-x2).powi(2) + (y1-y2).powi(2)).sqrt()),
+            _ => None
+        }
+    }
+}
+
+/// # Keyboard Costs
+/// A `CostModel` where substituting characters typed by nearby keys costs less than substituting
+/// characters typed far apart, for typo tolerant comparisons.
+///
+/// The substitution cost is `key_distance * scale`, capped at 1.0. Characters typed by the same key
+/// (e.g. `a` and `A`) cost `same_key_cost`, and characters not on the layout cost 1.0.
+/// Insertions, deletions and transpositions keep their unit cost.
+///
+/// ## Example
+/// ```
+/// use string_distance::{KeyboardCosts, KeyboardLayout, weighted_damerau_levenshtein_distance};
+///
+/// let costs = KeyboardCosts::new(KeyboardLayout::qwerty());
+/// let near = weighted_damerau_levenshtein_distance("shoes", "ahoes", &costs);
+/// let far = weighted_damerau_levenshtein_distance("shoes", "phoes", &costs);
+/// assert!(near < far);
+/// ```
+#[derive(Clone, Debug)]
+pub struct KeyboardCosts
+{
+    pub layout:KeyboardLayout,
+    pub scale:f64,
+    pub same_key_cost:f64
+}
+
+impl KeyboardCosts
+{
+    /// Creates a cost model for `layout`, where adjacent keys cost 0.5 and keys 2 or more apart cost 1.0.
+    pub fn new(layout:KeyboardLayout) -> KeyboardCosts {
+        KeyboardCosts { layout, scale: 0.5, same_key_cost: 0.25 }
+    }
+}
+
+impl CostModel for KeyboardCosts
+{
+    fn substitute_cost(&self, from:char, to:char) -> f64 {
+        if from == to {
+            return 0.0;
+        }
+        match self.layout.key_distance(from, to) {
+            Some(0.0) => self.same_key_cost,
+            Some(d) => (d * self.scale).min(1.0),
+            None => 1.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::levenshtein::weighted_levenshtein_distance;
+
+    static EPSILON:f64 = 0.001;
+    fn cmp_approx (actual:f64, expected:f64) -> bool { (actual - expected).abs() < EPSILON }
+
+    #[test]
+    fn adjacent_keys() {
+        let qwerty = KeyboardLayout::qwerty();
+        assert_eq!(qwerty.key_distance('a', 's'), Some(1.0));
+        assert_eq!(qwerty.key_distance('1', '2'), Some(1.0));
+        assert!(cmp_approx(qwerty.key_distance('q', 'a').unwrap(), 1.0308));
+    }
+
+    #[test]
+    fn unknown_keys() {
+        assert_eq!(KeyboardLayout::qwerty().key_distance('a', 'é'), None);
+        assert_eq!(KeyboardLayout::azerty().key_distance('a', 'é'), Some(0.5f64.hypot(1.0)));
+    }
+
+    #[test]
+    fn layouts_differ() {
+        assert_eq!(KeyboardLayout::qwerty().key_distance('q', 'w'), Some(1.0));
+        assert_eq!(KeyboardLayout::azerty().key_distance('a', 'z'), Some(1.0));
+        assert_eq!(KeyboardLayout::qwertz().key_distance('t', 'z'), Some(1.0));
+        assert_eq!(KeyboardLayout::dvorak().key_distance('a', 'o'), Some(1.0));
+        assert!(KeyboardLayout::qwerty().key_distance('a', 'o').unwrap() > 1.0);
+    }
+
+    #[test]
+    fn substitution_costs() {
+        let costs = KeyboardCosts::new(KeyboardLayout::qwerty());
+        assert_eq!(costs.substitute_cost('a', 'a'), 0.0);
+        assert_eq!(costs.substitute_cost('a', 'A'), 0.25);
+        assert_eq!(costs.substitute_cost('a', 's'), 0.5);
+        assert_eq!(costs.substitute_cost('a', 'p'), 1.0);
+        assert_eq!(costs.substitute_cost('a', '☃'), 1.0);
+    }
+
+    #[test]
+    fn typo_distance() {
+        let costs = KeyboardCosts::new(KeyboardLayout::qwerty());
+        assert_eq!(weighted_levenshtein_distance("shoes", "shoea", &costs), 0.5);
+        assert_eq!(weighted_levenshtein_distance("shoes", "shoep", &costs), 1.0);
+    }
+}
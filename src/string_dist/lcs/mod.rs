@@ -0,0 +1,480 @@
+use std::fmt;
+use std::cmp::max;
+use std::mem::swap;
+use std::ops::Range;
+use super::explain::{Explain, Silent};
+use super::levenshtein::CompactDistMatrix;
+use super::unit::Unit;
+
+mod suffix_automaton;
+mod suffix_array;
+mod ratcliff_obershelp;
+
+pub use self::ratcliff_obershelp::{ratcliff_obershelp_simularity, ratcliff_obershelp_matching_blocks};
+
+use self::suffix_automaton::SuffixAutomaton;
+use self::suffix_array::GeneralizedSuffixArray;
+
+// Longest Common Substring
+// LCS https://en.wikipedia.org/wiki/Longest_common_substring_problem
+
+/// # Match
+/// A common substring, and the char offsets it starts at in both strings.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Match {
+    pub str:String,
+    pub idx1:usize,
+    pub idx2:usize
+}
+
+impl Match {
+    /// The length of the substring, in chars.
+    pub fn len(&self) -> usize {
+        self.str.chars().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.str.is_empty()
+    }
+}
+
+impl fmt::Debug for Match {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} @ idx1:{}, @ idx2:{}", self.str, self.idx1, self.idx2)
+    }
+}
+
+/// # Longest Common Substrings
+/// Every occurrence of a longest substring shared by both strings, ordered by its char offset in `str1`
+/// and then in `str2`. A substring that occurs more than once is returned once per pair of offsets.
+/// Empty when the strings have no char in common.
+///
+/// ## Example
+/// ```
+/// use string_distance::lcs;
+///
+/// let matches = lcs::longest("Paul Jones", "Jones, Paul");
+/// assert_eq!(matches.len(), 1);
+/// assert_eq!(matches[0].str, "Jones");
+/// assert_eq!((matches[0].idx1, matches[0].idx2), (5, 0));
+///
+/// let matches = lcs::longest("abxcd", "cdyab");
+/// assert_eq!(matches.iter().map(|m| m.str.as_str()).collect::<Vec<_>>(), vec!["ab", "cd"]);
+/// ```
+///
+/// ## Complexity
+/// - Time:  O(|str1.len| * |str2.len|)
+/// - Space: O(min(|str1.len|, |str2.len|)) plus the matches
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * -> The longest common substrings, with their char offsets
+pub fn longest(str1:&str, str2:&str) -> Vec<Match>
+{
+    longest_explained(str1, str2, &mut Silent)
+}
+
+/// Identical to `longest`, but reports the substrings found to `explain`.
+pub fn longest_explained(str1:&str, str2:&str, explain:&mut dyn Explain) -> Vec<Match>
+{
+    let s1:Vec<char> = str1.chars().collect();
+    let s2:Vec<char> = str2.chars().collect();
+
+    // the rows run over the longer string, so that the matrix only holds the shorter one
+    let swapped = s2.len() > s1.len();
+    let (long, short) = if swapped { (&s2, &s1) } else { (&s1, &s2) };
+
+    // m[(i1, i2)] is the length of the common substring ending at long[i1] and short[i2]
+    let mut m:CompactDistMatrix<usize> = CompactDistMatrix::new(-1..long.len() as i32, -1..short.len() as i32, 2, 0);
+    let mut longest = 0;
+    let mut ends:Vec<(usize, usize)> = Vec::new();
+    for (i1, ch1) in (0i32..).zip(long.iter())
+    {
+        m[(i1, -1)] = 0;
+        for (i2, ch2) in (0i32..).zip(short.iter())
+        {
+            let score = if ch1 == ch2 { m[(i1-1, i2-1)] + 1 } else { 0 };
+            m[(i1, i2)] = score;
+
+            if score > longest {
+                longest = score;
+                ends.clear();
+            }
+            if score > 0 && score == longest {
+                ends.push(if swapped { (i2 as usize, i1 as usize) } else { (i1 as usize, i2 as usize) });
+            }
+        }
+    }
+    ends.sort();
+
+    let matches:Vec<Match> = ends.into_iter().map(|(end1, end2)| {
+        let (idx1, idx2) = (end1 + 1 - longest, end2 + 1 - longest);
+        Match { str: s1[idx1..=end1].iter().collect(), idx1, idx2 }
+    }).collect();
+
+    explain.state("Longest Common Substring", &matches);
+    matches
+}
+
+/// The first longest common substring of s1[r1] and s2[r2], as (idx1, idx2, len), preferring the earliest in s1
+/// and then in s2. A char of s2 that `can_match` rejects does not start or continue a match, but the longest
+/// match found is then extended over equal chars on both sides. When nothing matches, len is 0.
+fn longest_within(s1:&[char], s2:&[char], r1:Range<usize>, r2:Range<usize>, can_match:&dyn Fn(char) -> bool) -> (usize, usize, usize)
+{
+    let (lo1, hi1, lo2, hi2) = (r1.start, r1.end, r2.start, r2.end);
+
+    // m[(i1, i2)] is the length of the common substring ending at i1 and i2
+    let mut m:CompactDistMatrix<usize> = CompactDistMatrix::new(lo1 as i32 - 1..hi1 as i32, lo2 as i32 - 1..hi2 as i32, 2, 0);
+    let (mut best1, mut best2, mut len) = (lo1, lo2, 0);
+    for i1 in lo1 as i32..hi1 as i32
+    {
+        m[(i1, lo2 as i32 - 1)] = 0;
+        for i2 in lo2 as i32..hi2 as i32
+        {
+            let (ch1, ch2) = (s1[i1 as usize], s2[i2 as usize]);
+            let score = if ch1 == ch2 && can_match(ch2) { m[(i1-1, i2-1)] + 1 } else { 0 };
+            m[(i1, i2)] = score;
+
+            if score > len {
+                len = score;
+                best1 = i1 as usize + 1 - len;
+                best2 = i2 as usize + 1 - len;
+            }
+        }
+    }
+
+    while best1 > lo1 && best2 > lo2 && s1[best1-1] == s2[best2-1] {
+        best1 -= 1;
+        best2 -= 1;
+        len += 1;
+    }
+    while best1 + len < hi1 && best2 + len < hi2 && s1[best1+len] == s2[best2+len] {
+        len += 1;
+    }
+
+    (best1, best2, len)
+}
+
+/// # First Longest Common Substring
+/// The longest substring shared by both strings that ends first in `str2`, with the offset it first
+/// occurs at in `str1`. Unlike `longest`, only one occurrence is returned, and it is found with a suffix
+/// automaton of `str1` in linear time and memory, so it suits megabyte inputs such as log files or documents.
+/// None when the strings have no char in common.
+///
+/// ## Example
+/// ```
+/// use string_distance::lcs;
+///
+/// let m = lcs::longest_first("Paul Jones", "Jones, Paul").unwrap();
+/// assert_eq!((m.str.as_str(), m.idx1, m.idx2), ("Jones", 5, 0));
+/// assert!(lcs::longest_first("abc", "xyz").is_none());
+/// ```
+///
+/// ## Complexity
+/// - Time:  O(|str1.len| + |str2.len|), for a bounded alphabet
+/// - Space: O(|str1.len|)
+///
+/// ## Parameters
+/// * `str1` - The first string to compare, the automaton is built from this string
+/// * `str2` - The Second string to compare
+/// * -> The first longest common substring, with its char offsets
+pub fn longest_first(str1:&str, str2:&str) -> Option<Match>
+{
+    let s1:Vec<char> = str1.chars().collect();
+    let s2:Vec<char> = str2.chars().collect();
+
+    SuffixAutomaton::new(&s1).longest_common_substring(&s2).map(|(len, end1, end2)| {
+        let (idx1, idx2) = (end1 + 1 - len, end2 + 1 - len);
+        Match { str: s1[idx1..=end1].iter().collect(), idx1, idx2 }
+    })
+}
+
+/// # Shared Substring
+/// A substring shared by several strings, with the index of every string that contains it and the char
+/// offset it first occurs at in that string, ordered by string index.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SharedSubstring {
+    pub str:String,
+    pub occurrences:Vec<(usize, usize)>
+}
+
+/// # Longest Substring Shared by All
+/// The longest substring that occurs in every one of `strs`, e.g. the common part of a family of product SKUs.
+/// When several substrings are equally long, the lexicographically smallest is returned.
+/// None when there are no strings, or they have no char in common.
+///
+/// ## Example
+/// ```
+/// use string_distance::lcs;
+///
+/// let shared = lcs::longest_shared_by_all(&["ABX-1200-XL", "CD-1200-XLT", "1200-XL-RED"]).unwrap();
+/// assert_eq!(shared.str, "1200-XL");
+/// assert_eq!(shared.occurrences, vec![(0, 4), (1, 3), (2, 0)]);
+/// ```
+///
+/// ## Complexity
+/// Where n is the combined length of the strings
+/// - Time:  O(n * log(n)^2)
+/// - Space: O(n)
+pub fn longest_shared_by_all(strs:&[&str]) -> Option<SharedSubstring>
+{
+    longest_shared_by(strs, strs.len())
+}
+
+/// # Longest Substring Shared by k
+/// The longest substring that occurs in at least `k` of `strs`, e.g. the most common template of a set of log lines.
+/// `occurrences` lists every string that contains it, which can be more than `k`.
+/// When several substrings are equally long, the lexicographically smallest is returned.
+/// None when `k` is 0 or more than the number of strings, or no `k` strings share a char.
+///
+/// ## Example
+/// ```
+/// use string_distance::lcs;
+///
+/// let lines = ["user alice logged in", "user bob logged out", "disk full on /dev/sda"];
+/// let shared = lcs::longest_shared_by(&lines, 2).unwrap();
+/// assert_eq!(shared.str, " logged ");
+/// assert_eq!(shared.occurrences, vec![(0, 10), (1, 8)]);
+/// ```
+///
+/// ## Complexity
+/// Where n is the combined length of the strings
+/// - Time:  O(n * log(n)^2)
+/// - Space: O(n)
+pub fn longest_shared_by(strs:&[&str], k:usize) -> Option<SharedSubstring>
+{
+    let chars:Vec<Vec<char>> = strs.iter().map(|s| s.chars().collect()).collect();
+    let gsa = GeneralizedSuffixArray::new(&chars);
+
+    gsa.longest_shared(k).map(|(len, occurrences)| {
+        let (o, offset) = occurrences[0];
+        SharedSubstring { str: chars[o][offset..offset+len].iter().collect(), occurrences }
+    })
+}
+
+/// # Longest Common Substring Length
+/// The length of the longest substring shared by both strings, in chars.
+///
+/// ## Example
+/// ```
+/// use string_distance::lcs;
+///
+/// assert_eq!(lcs::length("Paul Jones", "Jones, Paul"), 5);
+/// ```
+pub fn length(str1:&str, str2:&str) -> usize
+{
+    length_by_unit(str1, str2, Unit::Chars)
+}
+
+/// # Longest Common Substring Distance
+/// The chars of the longest string that are not part of the longest common substring,
+/// `max(|str1.len|, |str2.len|) - length`.
+///
+/// ## Example
+/// ```
+/// use string_distance::lcs;
+///
+/// assert_eq!(lcs::distance("Paul Jones", "Jones, Paul"), 6);
+/// ```
+pub fn distance(str1:&str, str2:&str) -> usize
+{
+    max(str1.chars().count(), str2.chars().count()) - length(str1, str2)
+}
+
+/// # Longest Common Substring Simularity
+/// The length of the longest common substring divided by the length of the longest string,
+/// 0.0 <= simularity <= 1.0. Two empty strings are identical.
+///
+/// ## Example
+/// ```
+/// use string_distance::lcs;
+///
+/// assert_eq!(lcs::normalized_simularity("paul", "pauline"), 4.0 / 7.0);
+/// ```
+pub fn normalized_simularity(str1:&str, str2:&str) -> f64
+{
+    let longest = max(str1.chars().count(), str2.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    length(str1, str2) as f64 / longest as f64
+}
+
+/// # Longest Common Substring Length (by Unit)
+/// The length of the longest substring shared by both strings, counted in `unit`s, e.g. grapheme clusters or words.
+///
+/// ## Example
+/// ```
+/// use string_distance::{lcs, Unit};
+///
+/// assert_eq!(lcs::length_by_unit("Paul Jones", "Jones, Paul", Unit::Chars), 5);
+/// assert_eq!(lcs::length_by_unit("the quick brown fox", "a quick brown dog", Unit::Words), 2);
+/// ```
+///
+/// ## Complexity
+/// - Time:  O(|str1.len| * |str2.len|)
+/// - Space: O(min(|str1.len|, |str2.len|))
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `unit` - The unit the strings are compared in
+/// * -> The length of the longest common substring, in units
+pub fn length_by_unit(str1:&str, str2:&str, unit:Unit) -> usize
+{
+    let (mut s1, mut s2) = unit.symbols(str1, str2);
+    // the length is symmetric, so the matrix only needs to hold the shorter string
+    if s2.len() > s1.len() { swap(&mut s1, &mut s2); }
+
+    // m[(i1, i2)] is the length of the common substring ending at i1 and i2
+    let mut m:CompactDistMatrix<usize> = CompactDistMatrix::new(-1..s1.len() as i32, -1..s2.len() as i32, 2, 0);
+    let mut longest = 0;
+    for (i1, ch1) in (0i32..).zip(s1.iter())
+    {
+        m[(i1, -1)] = 0;
+        for (i2, ch2) in (0i32..).zip(s2.iter())
+        {
+            m[(i1, i2)] = if ch1 == ch2 { m[(i1-1, i2-1)] + 1 } else { 0 };
+            longest = max(longest, m[(i1, i2)]);
+        }
+    }
+
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::helpers::Lcg;
+
+    #[test]
+    fn empty_matches() {
+        assert!(longest("", "").is_empty());
+        assert!(longest("abc", "xyz").is_empty());
+        assert_eq!(distance("", "abc"), 3);
+        assert_eq!(normalized_simularity("", ""), 1.0);
+        assert_eq!(normalized_simularity("abc", "xyz"), 0.0);
+    }
+
+    #[test]
+    fn first_match_is_found() {
+        let matches = longest("failuree", "faluiere");
+        assert_eq!(matches, vec![
+            Match { str: "fa".to_string(), idx1: 0, idx2: 0 },
+            Match { str: "lu".to_string(), idx1: 3, idx2: 2 },
+            Match { str: "re".to_string(), idx1: 5, idx2: 6 }
+        ]);
+    }
+
+    #[test]
+    fn repeated_matches() {
+        let matches = longest("aaa", "aa");
+        let offsets:Vec<(usize, usize)> = matches.iter().map(|m| (m.idx1, m.idx2)).collect();
+        assert_eq!(offsets, vec![(0, 0), (1, 0)]);
+    }
+
+    #[test]
+    fn shorter_first_offsets() {
+        let offsets = |str1, str2| longest(str1, str2).iter().map(|m| (m.idx1, m.idx2)).collect::<Vec<_>>();
+        assert_eq!(offsets("abyab", "xxabyyab"), vec![(0, 2), (2, 5)]);
+        assert_eq!(offsets("xxabyyab", "abyab"), vec![(2, 0), (5, 2)]);
+        assert_eq!(length_by_unit("ab", "xxabyyab", Unit::Chars), 2);
+    }
+
+    #[test]
+    fn non_ascii_offsets() {
+        let matches = longest("crème brûlée", "une brûlure");
+        assert_eq!(matches, vec![Match { str: "e brûl".to_string(), idx1: 4, idx2: 2 }]);
+        assert_eq!(matches[0].len(), 6);
+    }
+
+    #[test]
+    fn longest_first_matches_dp() {
+        for seed in 0..50 {
+            let str1 = Lcg(seed).string(40 + seed as usize, "abcé");
+            let str2 = Lcg(seed + 100).string(30, "abcé");
+            let all = longest(&str1, &str2);
+            let first = longest_first(&str1, &str2);
+            assert_eq!(first.as_ref().map_or(0, |m| m.len()), length(&str1, &str2));
+
+            if let Some(m) = first {
+                let first_in_str2 = all.iter().min_by_key(|dp| (dp.idx2, dp.idx1)).unwrap();
+                assert_eq!(m.idx2, first_in_str2.idx2);
+                assert!(all.contains(&m), "{:?} is not one of {:?}", m, all);
+                assert!(all.iter().filter(|dp| dp.str == m.str).all(|dp| dp.idx1 >= m.idx1));
+            }
+        }
+    }
+
+    #[test]
+    fn longest_first_large_input() {
+        let shared = Lcg(7).string(1000, "abcdefghij");
+        let str1 = Lcg(1).string(200_000, "klmnop") + &shared + &Lcg(2).string(200_000, "klmnop");
+        let str2 = Lcg(3).string(100_000, "qrstuv") + &shared;
+        let m = longest_first(&str1, &str2).unwrap();
+        assert_eq!((m.len(), m.idx1, m.idx2), (1000, 200_000, 100_000));
+    }
+
+    #[test]
+    fn shared_by_none() {
+        assert_eq!(longest_shared_by_all(&[]), None);
+        assert_eq!(longest_shared_by_all(&["abc", "", "abc"]), None);
+        assert_eq!(longest_shared_by(&["abc", "abd"], 0), None);
+        assert_eq!(longest_shared_by(&["abc", "abd"], 3), None);
+        assert_eq!(longest_shared_by_all(&["abc", "xyz"]), None);
+    }
+
+    #[test]
+    fn shared_by_one() {
+        let shared = longest_shared_by(&["ab", "xyz", "c"], 1).unwrap();
+        assert_eq!(shared, SharedSubstring { str: "xyz".to_string(), occurrences: vec![(1, 0)] });
+    }
+
+    #[test]
+    fn shared_first_occurrences() {
+        let shared = longest_shared_by_all(&["née-née", "a née", "née"]).unwrap();
+        assert_eq!(shared.str, "née");
+        assert_eq!(shared.occurrences, vec![(0, 0), (1, 2), (2, 0)]);
+
+        let shared = longest_shared_by(&["xabcx", "yabcy", "zabz", "abc"], 2).unwrap();
+        assert_eq!(shared.str, "abc");
+        assert_eq!(shared.occurrences, vec![(0, 1), (1, 1), (3, 0)]);
+    }
+
+    #[test]
+    fn shared_by_two_matches_dp() {
+        for seed in 0..50 {
+            let str1 = Lcg(seed).string(30, "abc");
+            let str2 = Lcg(seed + 100).string(25, "abc");
+            let shared = longest_shared_by_all(&[&str1, &str2]);
+            assert_eq!(shared.as_ref().map_or(0, |s| s.str.chars().count()), length(&str1, &str2));
+            if let Some(shared) = shared {
+                assert!(longest(&str1, &str2).iter().any(|m| m.str == shared.str));
+            }
+        }
+    }
+
+    #[test]
+    fn empty_length() {
+        assert_eq!(length_by_unit("", "", Unit::Chars), 0);
+        assert_eq!(length_by_unit("abc", "", Unit::Chars), 0);
+    }
+
+    #[test]
+    fn long_common_substring() {
+        let str1 = "x".repeat(300) + "abc";
+        let str2 = "y".to_string() + &"x".repeat(300);
+        assert_eq!(length_by_unit(&str1, &str2, Unit::Chars), 300);
+        let matches = longest(&str1, &str2);
+        assert_eq!(matches.len(), 1);
+        assert_eq!((matches[0].len(), matches[0].idx1, matches[0].idx2), (300, 0, 1));
+    }
+
+    #[test]
+    fn unit_lengths() {
+        assert_eq!(length_by_unit("née", "ne\u{301}e", Unit::Chars), 1);
+        assert_eq!(length_by_unit("ne\u{301}e", "e\u{301}e", Unit::Graphemes), 2);
+        assert_eq!(length_by_unit("ne\u{301}e", "e\u{301}e", Unit::Bytes), 4);
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::{HashMap, HashSet};
+use super::{Match, longest_within};
+
+/// Strings of at least this many chars have their popular chars ignored by autojunk, as in difflib.
+static AUTOJUNK_MIN_LEN:usize = 200;
+
+/// # Ratcliff/Obershelp Simularity
+/// Gestalt pattern matching, the ratio of Python's `difflib.SequenceMatcher(None, str1, str2).ratio()`.
+/// The longest common substring is matched first, then the same is repeated on the unmatched parts to its
+/// left and right. The simularity is `2 * matched / (|str1.len| + |str2.len|)`.
+///
+/// See - https://en.wikipedia.org/wiki/Gestalt_pattern_matching
+/// See - https://docs.python.org/3/library/difflib.html#sequencematcher-objects
+///
+/// ## Example
+/// ```
+/// use string_distance::ratcliff_obershelp_simularity;
+///
+/// let sim = ratcliff_obershelp_simularity("private Thread currentThread;", "private volatile Thread currentThread;", true);
+/// assert_eq!(sim, 58.0 / 67.0);
+/// ```
+///
+/// ## Complexity
+/// - Time:  O(|str1.len| * |str2.len|) per matching block, O(|str1.len|^2 * |str2.len|) at worst
+/// - Space: O(|str1.len| + |str2.len|)
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `autojunk` - difflib's heuristic, on by default in Python. When `str2` has at least 200 chars, chars
+///   making up more than 1% of it cannot start a match, but can still extend one
+/// * -> The Ratcliff/Obershelp Simularity of the passed 2 strings, 0.0 <= simularity <= 1.0
+pub fn ratcliff_obershelp_simularity(str1:&str, str2:&str, autojunk:bool) -> f64
+{
+    let len = str1.chars().count() + str2.chars().count();
+    if len == 0 {
+        return 1.0;
+    }
+    let matched:usize = ratcliff_obershelp_matching_blocks(str1, str2, autojunk).iter().map(|block| block.len()).sum();
+    2.0 * matched as f64 / len as f64
+}
+
+/// # Ratcliff/Obershelp Matching Blocks
+/// The blocks matched by `ratcliff_obershelp_simularity`, as `difflib.SequenceMatcher.get_matching_blocks()`
+/// returns them. The blocks are ordered by their char offsets, adjacent blocks are merged, and the last block
+/// is always the empty block at the end of both strings.
+///
+/// ## Example
+/// ```
+/// use string_distance::ratcliff_obershelp_matching_blocks;
+///
+/// let blocks = ratcliff_obershelp_matching_blocks("abxcd", "abcd", true);
+/// let blocks:Vec<(usize, usize, usize)> = blocks.iter().map(|b| (b.idx1, b.idx2, b.len())).collect();
+/// assert_eq!(blocks, vec![(0, 0, 2), (3, 2, 2), (5, 4, 0)]);
+/// ```
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `autojunk` - Whether to ignore the popular chars of a long `str2`, see `ratcliff_obershelp_simularity`
+/// * -> The matching blocks, with their char offsets
+pub fn ratcliff_obershelp_matching_blocks(str1:&str, str2:&str, autojunk:bool) -> Vec<Match>
+{
+    let s1:Vec<char> = str1.chars().collect();
+    let s2:Vec<char> = str2.chars().collect();
+    let popular = if autojunk { popular_chars(&s2) } else { HashSet::new() };
+    let can_match = |ch:char| !popular.contains(&ch);
+
+    // Match the longest block of each range, then queue the ranges on either side of it
+    let mut blocks:Vec<(usize, usize, usize)> = Vec::new();
+    let mut queue = vec![(0, s1.len(), 0, s2.len())];
+    while let Some((lo1, hi1, lo2, hi2)) = queue.pop()
+    {
+        let (idx1, idx2, len) = longest_within(&s1, &s2, lo1..hi1, lo2..hi2, &can_match);
+        if len > 0 {
+            blocks.push((idx1, idx2, len));
+            if lo1 < idx1 && lo2 < idx2 {
+                queue.push((lo1, idx1, lo2, idx2));
+            }
+            if idx1 + len < hi1 && idx2 + len < hi2 {
+                queue.push((idx1 + len, hi1, idx2 + len, hi2));
+            }
+        }
+    }
+    blocks.sort();
+
+    // Merge adjacent blocks, and end with the empty block
+    let mut merged:Vec<(usize, usize, usize)> = Vec::new();
+    for (idx1, idx2, len) in blocks
+    {
+        match merged.last_mut() {
+            Some(last) if last.0 + last.2 == idx1 && last.1 + last.2 == idx2 => last.2 += len,
+            _ => merged.push((idx1, idx2, len))
+        }
+    }
+    merged.push((s1.len(), s2.len(), 0));
+
+    merged.into_iter().map(|(idx1, idx2, len)| {
+        Match { str: s1[idx1..idx1+len].iter().collect(), idx1, idx2 }
+    }).collect()
+}
+
+/// The chars that make up more than 1% of `s`, when `s` is long enough for autojunk.
+fn popular_chars(s:&[char]) -> HashSet<char>
+{
+    if s.len() < AUTOJUNK_MIN_LEN {
+        return HashSet::new();
+    }
+
+    let mut counts:HashMap<char, usize> = HashMap::new();
+    for &ch in s { *counts.entry(ch).or_insert(0) += 1; }
+
+    let max_count = s.len() / 100 + 1;
+    counts.into_iter().filter(|&(_, count)| count > max_count).map(|(ch, _)| ch).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocks(str1:&str, str2:&str, autojunk:bool) -> Vec<(usize, usize, usize)> {
+        ratcliff_obershelp_matching_blocks(str1, str2, autojunk).iter().map(|b| (b.idx1, b.idx2, b.len())).collect()
+    }
+
+    #[test]
+    fn empty_strings() {
+        assert_eq!(ratcliff_obershelp_simularity("", "", true), 1.0);
+        assert_eq!(ratcliff_obershelp_simularity("abc", "", true), 0.0);
+        assert_eq!(blocks("", "", true), vec![(0, 0, 0)]);
+    }
+
+    // Expected values from Python's difflib.SequenceMatcher
+    #[test]
+    fn difflib_matching_blocks() {
+        assert_eq!(blocks("failuree", "faluiere", true), vec![(0, 0, 2), (3, 2, 2), (5, 6, 2), (8, 8, 0)]);
+        assert_eq!(blocks("Paul Jones", "Jones, Paul", true), vec![(5, 0, 5), (10, 11, 0)]);
+        assert_eq!(blocks("crème brûlée", "creme brulee", true), vec![(0, 0, 2), (3, 3, 5), (9, 9, 1), (11, 10, 1), (12, 12, 0)]);
+        assert_eq!(ratcliff_obershelp_simularity("failuree", "faluiere", true), 0.75);
+        assert_eq!(ratcliff_obershelp_simularity("Paul Jones", "Jones, Paul", true), 10.0 / 21.0);
+    }
+
+    #[test]
+    fn merges_adjacent_blocks() {
+        let str1 = "ab".repeat(3);
+        let merged = ratcliff_obershelp_matching_blocks(&str1, &str1, false);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].str, str1);
+    }
+
+    #[test]
+    fn autojunk_popular_chars() {
+        let str1 = "ab".repeat(150);
+        let str2 = "ba".repeat(150);
+        assert_eq!(ratcliff_obershelp_simularity(&str1, &str2, true), 0.0);
+        assert!(ratcliff_obershelp_simularity(&str1, &str2, false) > 0.99);
+
+        // Popular chars still extend a match of other chars
+        let str2 = "x".to_string() + &"a".repeat(250);
+        assert_eq!(blocks("xaa", &str2, true), vec![(0, 0, 3), (3, 251, 0)]);
+    }
+}
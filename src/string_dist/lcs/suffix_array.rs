@@ -0,0 +1,216 @@
+use std::collections::VecDeque;
+
+/// Marks a position of the text that belongs to no string, i.e. a separator.
+const NONE:usize = usize::MAX;
+
+/// The first symbol used for separators, one past the largest char.
+const FIRST_SEPARATOR:u32 = 0x110000;
+
+/// # Generalized Suffix Array
+/// The sorted suffixes of several strings, concatenated with a distinct separator after each string so
+/// that no common prefix runs from one string into the next, together with the longest common prefix
+/// of every pair of neighbouring suffixes.
+///
+/// Suffixes are sorted by prefix doubling, and the common prefixes found with Kasai's algorithm.
+///
+/// See - https://en.wikipedia.org/wiki/Generalized_suffix_array
+pub struct GeneralizedSuffixArray
+{
+    /// The string each position of the text belongs to, NONE for separators
+    owner:Vec<usize>,
+    /// The position of the text each string starts at
+    starts:Vec<usize>,
+    /// The position of each string's separator
+    ends:Vec<usize>,
+    /// The start of every suffix, in sorted order
+    sa:Vec<usize>,
+    /// lcp[i] is the length of the common prefix of the suffixes sa[i-1] and sa[i], lcp[0] is 0
+    lcp:Vec<usize>
+}
+
+impl GeneralizedSuffixArray
+{
+    pub fn new(strs:&[Vec<char>]) -> GeneralizedSuffixArray
+    {
+        // The strings' chars as u32, each string followed by its separator
+        let mut text:Vec<u32> = Vec::new();
+        let mut owner:Vec<usize> = Vec::new();
+        let mut starts:Vec<usize> = Vec::with_capacity(strs.len());
+        let mut ends:Vec<usize> = Vec::with_capacity(strs.len());
+        for (id, s) in strs.iter().enumerate()
+        {
+            starts.push(text.len());
+            text.extend(s.iter().map(|&ch| ch as u32));
+            owner.extend(s.iter().map(|_| id));
+            ends.push(text.len());
+            text.push(FIRST_SEPARATOR + id as u32);
+            owner.push(NONE);
+        }
+
+        let sa = suffix_array(&text);
+        let lcp = lcp_array(&text, &sa);
+        GeneralizedSuffixArray { owner, starts, ends, sa, lcp }
+    }
+
+    /// The longest substring shared by at least `k` of the strings, as its length and the (string, char offset)
+    /// of its first occurrence in every string that contains it, ordered by string.
+    /// When several substrings are equally long, the one that sorts first is returned.
+    /// None if `k` is 0, more than the number of strings, or no substring is shared by `k` strings.
+    pub fn longest_shared(&self, k:usize) -> Option<(usize, Vec<(usize, usize)>)>
+    {
+        let n = self.sa.len();
+        if k == 0 || k > self.starts.len() {
+            return None;
+        }
+
+        // Slide a window over the suffixes, shrinking it from the left while it still spans k strings.
+        // The common prefix of the window is the minimum lcp within it, tracked with a monotonic deque.
+        let mut counts:Vec<usize> = vec![0; self.starts.len()];
+        let mut distinct = 0;
+        let mut mins:VecDeque<usize> = VecDeque::new();
+        let mut l = 0;
+        let mut best:Option<(usize, usize)> = None;
+        for r in 0..n
+        {
+            let o = self.owner[self.sa[r]];
+            if o != NONE {
+                counts[o] += 1;
+                if counts[o] == 1 { distinct += 1; }
+            }
+            if r > 0 {
+                while mins.back().is_some_and(|&i| self.lcp[i] >= self.lcp[r]) { mins.pop_back(); }
+                mins.push_back(r);
+            }
+
+            while l < r {
+                let o = self.owner[self.sa[l]];
+                if o != NONE && counts[o] == 1 && distinct <= k { break; }
+                if o != NONE {
+                    counts[o] -= 1;
+                    if counts[o] == 0 { distinct -= 1; }
+                }
+                l += 1;
+                while mins.front().is_some_and(|&i| i <= l) { mins.pop_front(); }
+            }
+
+            if distinct >= k {
+                let len = match mins.front() {
+                    Some(&i) if l < r => self.lcp[i],
+                    _ => self.ends[self.owner[self.sa[l]]] - self.sa[l]
+                };
+                if len > best.map_or(0, |(best_len, _)| best_len) {
+                    best = Some((len, l));
+                }
+            }
+        }
+
+        best.map(|(len, l)| (len, self.occurrences(l, len)))
+    }
+
+    /// The first occurrence in every string of the prefix of length `len` of the suffix sa[i].
+    fn occurrences(&self, i:usize, len:usize) -> Vec<(usize, usize)>
+    {
+        // Every suffix sharing the prefix is a neighbour of sa[i] with a common prefix of at least len
+        let (mut lo, mut hi) = (i, i);
+        while lo > 0 && self.lcp[lo] >= len { lo -= 1; }
+        while hi + 1 < self.sa.len() && self.lcp[hi+1] >= len { hi += 1; }
+
+        let mut first:Vec<Option<usize>> = vec![None; self.starts.len()];
+        for &p in &self.sa[lo..=hi]
+        {
+            let o = self.owner[p];
+            let offset = p - self.starts[o];
+            if first[o].is_none_or(|f| offset < f) {
+                first[o] = Some(offset);
+            }
+        }
+        first.iter().enumerate().filter_map(|(o, f)| f.map(|offset| (o, offset))).collect()
+    }
+}
+
+/// Sorts the suffixes of `text` by prefix doubling, every round sorts by the ranks of the first 2k symbols.
+fn suffix_array(text:&[u32]) -> Vec<usize>
+{
+    let n = text.len();
+    let mut sa:Vec<usize> = (0..n).collect();
+    let mut rank:Vec<usize> = text.iter().map(|&c| c as usize).collect();
+    let mut next:Vec<usize> = vec![0; n];
+    let mut k = 1;
+    if n == 0 {
+        return sa;
+    }
+    loop
+    {
+        {
+            let key = |i:usize| (rank[i], if i + k < n { rank[i+k] + 1 } else { 0 });
+            sa.sort_unstable_by_key(|&i| key(i));
+            next[sa[0]] = 0;
+            for j in 1..n
+            {
+                next[sa[j]] = next[sa[j-1]] + if key(sa[j-1]) < key(sa[j]) { 1 } else { 0 };
+            }
+        }
+        ::std::mem::swap(&mut rank, &mut next);
+
+        // Stop once every suffix has its own rank
+        if rank[sa[n-1]] == n - 1 { break; }
+        k *= 2;
+    }
+    sa
+}
+
+/// Kasai's algorithm, the common prefix of each suffix with the suffix before it in `sa`.
+fn lcp_array(text:&[u32], sa:&[usize]) -> Vec<usize>
+{
+    let n = text.len();
+    let mut rank:Vec<usize> = vec![0; n];
+    for (i, &p) in sa.iter().enumerate() { rank[p] = i; }
+
+    let mut lcp:Vec<usize> = vec![0; n];
+    let mut h = 0;
+    for p in 0..n
+    {
+        if rank[p] == 0 {
+            h = 0;
+            continue;
+        }
+        let q = sa[rank[p] - 1];
+        while p + h < n && q + h < n && text[p+h] == text[q+h] { h += 1; }
+        lcp[rank[p]] = h;
+        h = h.saturating_sub(1);
+    }
+    lcp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gsa(strs:&[&str]) -> GeneralizedSuffixArray {
+        let strs:Vec<Vec<char>> = strs.iter().map(|s| s.chars().collect()).collect();
+        GeneralizedSuffixArray::new(&strs)
+    }
+
+    #[test]
+    fn suffixes_are_sorted() {
+        let text:Vec<u32> = "banana".chars().map(|ch| ch as u32).collect();
+        let sa = suffix_array(&text);
+        assert_eq!(sa, vec![5, 3, 1, 0, 4, 2]);
+        assert_eq!(lcp_array(&text, &sa), vec![0, 1, 3, 0, 0, 2]);
+    }
+
+    #[test]
+    fn separators_end_common_prefixes() {
+        let g = gsa(&["ab", "ab"]);
+        assert_eq!(g.lcp.iter().max(), Some(&2));
+        assert_eq!(g.longest_shared(2), Some((2, vec![(0, 0), (1, 0)])));
+    }
+
+    #[test]
+    fn single_string_window() {
+        let g = gsa(&["abc", "de"]);
+        assert_eq!(g.longest_shared(1), Some((3, vec![(0, 0)])));
+        assert_eq!(g.longest_shared(2), None);
+        assert_eq!(g.longest_shared(3), None);
+    }
+}
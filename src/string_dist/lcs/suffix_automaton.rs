@@ -0,0 +1,191 @@
+/// Marks a missing suffix link or the end of a list of edges.
+const NONE:u32 = u32::MAX;
+
+/// A state of the automaton, the set of substrings that end at the same positions of the string.
+struct State
+{
+    /// The length of the longest substring of the state
+    len:u32,
+    /// The state of the longest suffix that ends at more positions
+    link:u32,
+    /// The position the substrings of the state first end at
+    first_end:u32,
+    /// The head of the state's list of edges
+    edges:u32
+}
+
+/// A transition on `symbol` to `target`, and the next edge of the same state.
+struct Edge
+{
+    symbol:char,
+    target:u32,
+    next:u32
+}
+
+/// # Suffix Automaton
+/// The smallest automaton that accepts every suffix of a string, built in linear time.
+/// Every substring of the string is a path from the root, so a second string can be walked through it
+/// to find the longest substring ending at each of its positions.
+///
+/// The edges of all states are kept in a single list per automaton rather than a map per state,
+/// which keeps the memory to a few dozen bytes per char for megabyte strings.
+///
+/// See - https://cp-algorithms.com/string/suffix-automaton.html
+pub struct SuffixAutomaton
+{
+    states:Vec<State>,
+    edges:Vec<Edge>
+}
+
+impl SuffixAutomaton
+{
+    pub fn new(s:&[char]) -> SuffixAutomaton
+    {
+        let mut sa = SuffixAutomaton {
+            states: Vec::with_capacity(2 * s.len() + 1),
+            edges: Vec::with_capacity(3 * s.len())
+        };
+        sa.states.push(State { len: 0, link: NONE, first_end: 0, edges: NONE });
+
+        let mut last = 0;
+        for (pos, &ch) in s.iter().enumerate()
+        {
+            last = sa.extend(last, ch, pos as u32);
+        }
+        sa
+    }
+
+    /// Appends `ch`, at `pos`, to the string accepted by the automaton whose whole string ends in state `last`.
+    /// Returns the new state of the whole string.
+    fn extend(&mut self, last:u32, ch:char, pos:u32) -> u32
+    {
+        let cur = self.add_state(self.states[last as usize].len + 1, pos, NONE);
+
+        let mut p = last;
+        while p != NONE && self.transition(p, ch).is_none() {
+            self.set_transition(p, ch, cur);
+            p = self.states[p as usize].link;
+        }
+
+        if p == NONE {
+            self.states[cur as usize].link = 0;
+            return cur;
+        }
+
+        let q = self.transition(p, ch).unwrap();
+        if self.states[p as usize].len + 1 == self.states[q as usize].len {
+            self.states[cur as usize].link = q;
+            return cur;
+        }
+
+        // q also holds longer substrings that do not end at pos, split off its shorter ones
+        let clone = self.add_state(self.states[p as usize].len + 1, self.states[q as usize].first_end, q);
+        self.states[clone as usize].link = self.states[q as usize].link;
+        while p != NONE && self.transition(p, ch) == Some(q) {
+            self.set_transition(p, ch, clone);
+            p = self.states[p as usize].link;
+        }
+        self.states[q as usize].link = clone;
+        self.states[cur as usize].link = clone;
+        cur
+    }
+
+    /// Adds a state, with a copy of the edges of `copy_from` unless it is `NONE`.
+    fn add_state(&mut self, len:u32, first_end:u32, copy_from:u32) -> u32
+    {
+        let id = self.states.len() as u32;
+        self.states.push(State { len, link: NONE, first_end, edges: NONE });
+
+        if copy_from != NONE {
+            let mut e = self.states[copy_from as usize].edges;
+            while e != NONE {
+                let (symbol, target, next) = (self.edges[e as usize].symbol, self.edges[e as usize].target, self.edges[e as usize].next);
+                self.set_transition(id, symbol, target);
+                e = next;
+            }
+        }
+        id
+    }
+
+    fn transition(&self, state:u32, ch:char) -> Option<u32>
+    {
+        let mut e = self.states[state as usize].edges;
+        while e != NONE {
+            let edge = &self.edges[e as usize];
+            if edge.symbol == ch {
+                return Some(edge.target);
+            }
+            e = edge.next;
+        }
+        None
+    }
+
+    fn set_transition(&mut self, state:u32, ch:char, target:u32)
+    {
+        let mut e = self.states[state as usize].edges;
+        while e != NONE {
+            if self.edges[e as usize].symbol == ch {
+                self.edges[e as usize].target = target;
+                return;
+            }
+            e = self.edges[e as usize].next;
+        }
+
+        let head = self.states[state as usize].edges;
+        self.states[state as usize].edges = self.edges.len() as u32;
+        self.edges.push(Edge { symbol: ch, target, next: head });
+    }
+
+    /// The longest substring shared with `s2` that ends first in `s2`, as (length, end in the automaton's
+    /// string, end in `s2`). The end in the automaton's string is where the substring first ends.
+    /// None if no char is shared.
+    pub fn longest_common_substring(&self, s2:&[char]) -> Option<(usize, usize, usize)>
+    {
+        let mut best:Option<(usize, usize, usize)> = None;
+        let (mut v, mut l) = (0u32, 0u32);
+        for (i2, &ch) in s2.iter().enumerate()
+        {
+            // Shorten the match until it can be extended by ch
+            while v != 0 && self.transition(v, ch).is_none() {
+                v = self.states[v as usize].link;
+                l = self.states[v as usize].len;
+            }
+
+            match self.transition(v, ch) {
+                Some(next) => { v = next; l += 1; },
+                None => { v = 0; l = 0; }
+            }
+
+            if l > 0 && best.is_none_or(|(len, _, _)| l as usize > len) {
+                best = Some((l as usize, self.states[v as usize].first_end as usize, i2));
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(s:&str) -> Vec<char> { s.chars().collect() }
+
+    #[test]
+    fn accepts_every_substring() {
+        let s = chars("abcbc");
+        let sa = SuffixAutomaton::new(&s);
+        for i in 0..s.len() {
+            for j in i+1..=s.len() {
+                assert_eq!(sa.longest_common_substring(&s[i..j]).map(|(len, _, _)| len), Some(j - i));
+            }
+        }
+        assert!(sa.states.len() < 2 * s.len());
+    }
+
+    #[test]
+    fn first_end_positions() {
+        let sa = SuffixAutomaton::new(&chars("xabyab"));
+        assert_eq!(sa.longest_common_substring(&chars("zab")), Some((2, 2, 2)));
+        assert_eq!(sa.longest_common_substring(&chars("zzz")), None);
+    }
+}
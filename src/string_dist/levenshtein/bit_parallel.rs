@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::mem::swap;
+
+/// The match vectors of a pattern, bit `i` of word `w` is set if `pattern[w*64 + i] == ch`.
+struct PatternMatch
+{
+    words:usize,
+    peq:HashMap<char, Vec<u64>>
+}
+
+impl PatternMatch
+{
+    fn new(pattern:&[char]) -> PatternMatch {
+        let words = pattern.len().div_ceil(64);
+        let mut peq:HashMap<char, Vec<u64>> = HashMap::new();
+        for (i, ch) in pattern.iter().enumerate() {
+            peq.entry(*ch).or_insert_with(|| vec![0; words])[i / 64] |= 1 << (i % 64);
+        }
+        PatternMatch { words, peq }
+    }
+
+    fn get(&self, word:usize, ch:char) -> u64 {
+        self.peq.get(&ch).map_or(0, |v| v[word])
+    }
+}
+
+/// The bit vectors of a single 64 row block of the (implicit) distance matrix column.
+#[derive(Clone, Copy)]
+struct Block
+{
+    vp:u64,
+    vn:u64
+}
+
+/// A `Block` of the OSA distance, which also keeps the diagonal and the match vector of its column
+/// for the transpositions of the next column.
+#[derive(Clone, Copy)]
+struct OsaBlock
+{
+    vp:u64,
+    vn:u64,
+    d0:u64,
+    pm:u64
+}
+
+/// Orders the strings so that the shorter one is the pattern, both distances are symmetric.
+fn pattern_and_text<'a>(s1:&'a [char], s2:&'a [char]) -> (&'a [char], &'a [char]) {
+    if s1.len() <= s2.len() { (s1, s2) } else { (s2, s1) }
+}
+
+/// # Myers' Bit-Parallel Levenshtein Distance
+/// Computes the Levenshtein Distance 64 matrix cells at a time, using Hyyrö's formulation of Myers' algorithm
+/// with one block of 64 rows per word of the pattern.
+///
+/// See - Myers, G. (1999). A fast bit-vector algorithm for approximate string matching based on dynamic programming.
+/// See - Hyyrö, H. (2003). A bit-vector algorithm for computing Levenshtein and Damerau edit distances.
+///
+/// ## Complexity
+/// - Time:  O(⌈min(|str1.len|, |str2.len|)/64⌉ * max(|str1.len|, |str2.len|))
+/// - Space: O(min(|str1.len|, |str2.len|))
+pub fn levenshtein_bit_parallel(s1:&[char], s2:&[char]) -> usize
+{
+    let (pattern, text) = pattern_and_text(s1, s2);
+    if pattern.is_empty() { return text.len(); }
+
+    let pm = PatternMatch::new(pattern);
+    let last = 1u64 << ((pattern.len() - 1) % 64);
+    let mut blocks = vec![Block { vp: !0, vn: 0 }; pm.words];
+    let mut score = pattern.len();
+
+    for ch in text.iter()
+    {
+        // the first row of the matrix increases by 1 in every column
+        let mut hp_carry = 1u64;
+        let mut hn_carry = 0u64;
+
+        for (word, block) in blocks.iter_mut().enumerate()
+        {
+            let x = pm.get(word, *ch) | hn_carry;
+            let d0 = (((x & block.vp).wrapping_add(block.vp)) ^ block.vp) | x | block.vn;
+            let mut hp = block.vn | !(d0 | block.vp);
+            let mut hn = d0 & block.vp;
+
+            if word == pm.words - 1 {
+                if hp & last != 0 { score += 1; }
+                if hn & last != 0 { score -= 1; }
+            }
+
+            let (hp_in, hn_in) = (hp_carry, hn_carry);
+            hp_carry = hp >> 63;
+            hn_carry = hn >> 63;
+            hp = (hp << 1) | hp_in;
+            hn = (hn << 1) | hn_in;
+
+            block.vp = hn | !(d0 | hp);
+            block.vn = hp & d0;
+        }
+    }
+
+    score
+}
+
+/// # Hyyrö's Bit-Parallel Optimal String Alignment Distance
+/// Extends `levenshtein_bit_parallel` with the transposition of adjacent characters, by carrying the
+/// diagonal and the match vector of the previous column.
+///
+/// See - Hyyrö, H. (2003). A bit-vector algorithm for computing Levenshtein and Damerau edit distances.
+///
+/// ## Complexity
+/// - Time:  O(⌈min(|str1.len|, |str2.len|)/64⌉ * max(|str1.len|, |str2.len|))
+/// - Space: O(min(|str1.len|, |str2.len|))
+pub fn osa_bit_parallel(s1:&[char], s2:&[char]) -> usize
+{
+    let (pattern, text) = pattern_and_text(s1, s2);
+    if pattern.is_empty() { return text.len(); }
+
+    let pm = PatternMatch::new(pattern);
+    let last = 1u64 << ((pattern.len() - 1) % 64);
+
+    // offset by 1, block 0 is an empty block preceding the first word, so every word has a previous block
+    let empty = OsaBlock { vp: 0, vn: 0, d0: 0, pm: 0 };
+    let mut old = vec![OsaBlock { vp: !0, ..empty }; pm.words + 1];
+    let mut new = old.clone();
+    old[0] = empty;
+    new[0] = empty;
+    let mut score = pattern.len();
+
+    for ch in text.iter()
+    {
+        let mut hp_carry = 1u64;
+        let mut hn_carry = 0u64;
+
+        for word in 0..pm.words
+        {
+            let pm_j = pm.get(word, *ch);
+            let prev = old[word+1];
+
+            let x = pm_j | hn_carry;
+            // transpositions, the shifted in bit comes from the previous word
+            let tr = ((((!prev.d0) & pm_j) << 1) | (((!old[word].d0) & new[word].pm) >> 63)) & prev.pm;
+
+            let d0 = (((x & prev.vp).wrapping_add(prev.vp)) ^ prev.vp) | x | prev.vn | tr;
+            let mut hp = prev.vn | !(d0 | prev.vp);
+            let mut hn = d0 & prev.vp;
+
+            if word == pm.words - 1 {
+                if hp & last != 0 { score += 1; }
+                if hn & last != 0 { score -= 1; }
+            }
+
+            let (hp_in, hn_in) = (hp_carry, hn_carry);
+            hp_carry = hp >> 63;
+            hn_carry = hn >> 63;
+            hp = (hp << 1) | hp_in;
+            hn = (hn << 1) | hn_in;
+
+            new[word+1] = OsaBlock { vp: hn | !(d0 | hp), vn: hp & d0, d0, pm: pm_j };
+        }
+
+        swap(&mut old, &mut new);
+    }
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::explain::Silent;
+    use super::super::super::helpers::Lcg;
+    use super::super::levenshtein::{levenshtein_distance_explained, osa_distance_explained};
+
+    fn chars(s:&str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn empty_distance() {
+        assert_eq!(levenshtein_bit_parallel(&[], &[]), 0);
+        assert_eq!(levenshtein_bit_parallel(&chars("abc"), &[]), 3);
+        assert_eq!(osa_bit_parallel(&[], &chars("abc")), 3);
+    }
+
+    #[test]
+    fn known_distances() {
+        assert_eq!(levenshtein_bit_parallel(&chars("jones"), &chars("johnson")), 4);
+        assert_eq!(levenshtein_bit_parallel(&chars("paul"), &chars("pual")), 2);
+        assert_eq!(osa_bit_parallel(&chars("paul"), &chars("pual")), 1);
+        assert_eq!(osa_bit_parallel(&chars("ca"), &chars("abc")), 3);
+        assert_eq!(osa_bit_parallel(&chars("Paul Jones"), &chars("Jones, Paul")), 11);
+    }
+
+    #[test]
+    fn matches_matrix_implementation() {
+        let mut rng = Lcg(42);
+        let alphabets = ["ab", "abcd", "abcdefghijklmnopqrstuvwxyz", "aé🦀"];
+        for alphabet in alphabets.iter() {
+            for &(len1, len2) in [(1, 1), (5, 9), (63, 64), (64, 64), (65, 70), (100, 90), (129, 140), (200, 20)].iter() {
+                let s1 = rng.string(len1, alphabet);
+                let s2 = rng.string(len2, alphabet);
+                assert_eq!(levenshtein_bit_parallel(&chars(&s1), &chars(&s2)), levenshtein_distance_explained(&s1, &s2, &mut Silent), "{} -> {}", s1, s2);
+                assert_eq!(osa_bit_parallel(&chars(&s1), &chars(&s2)), osa_distance_explained(&s1, &s2, &mut Silent), "{} -> {}", s1, s2);
+            }
+        }
+
+        // Multi-word patterns over small alphabets, where transpositions are frequent
+        for alphabet in ["ab", "abc", "abcd"].iter() {
+            for _ in 0..1000 {
+                let (len1, len2) = (65 + rng.next(136), 65 + rng.next(136));
+                let s1 = rng.string(len1, alphabet);
+                let s2 = rng.string(len2, alphabet);
+                assert_eq!(osa_bit_parallel(&chars(&s1), &chars(&s2)), osa_distance_explained(&s1, &s2, &mut Silent), "{} -> {}", s1, s2);
+            }
+        }
+    }
+
+    #[test]
+    fn transpositions_across_words() {
+        let s1:String = (0..130).map(|i| if i % 2 == 0 {'a'} else {'b'}).collect();
+        let s2:String = (0..130).map(|i| if i % 2 == 0 {'b'} else {'a'}).collect();
+        assert_eq!(osa_bit_parallel(&chars(&s1), &chars(&s2)), osa_distance_explained(&s1, &s2, &mut Silent));
+        assert_eq!(osa_bit_parallel(&chars(&s1), &chars(&s2)), 2);
+
+        let s1 = "ccabacbcbaacbbbbbacaacacbbacbbabcbbbbcabcbcaccccabccbaaabcacbbcabcbacabccccbc";
+        let s2 = "accbbabaaaabacaacccaabccaaccabcbaaabcbccabbcacbbacbbbacacbcaacbabcacaaaaabccacbbac";
+        assert_eq!(osa_bit_parallel(&chars(s1), &chars(s2)), 38);
+    }
+}
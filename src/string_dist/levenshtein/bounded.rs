@@ -0,0 +1,219 @@
+use std::cmp::{min, max};
+use std::collections::HashMap;
+use std::mem::swap;
+use super::dist_matrix::{DistMatrix, CompactDistMatrix};
+
+/// # Bounded Levenshtein Distance
+/// Calculates the Levenshtein Distance, but only if it is no larger than `max_dist`.
+/// Only the diagonal band of the matrix within `max_dist` of the main diagonal is filled (Ukkonen's cut-off), and the
+/// calculation stops as soon as every cell of a row exceeds `max_dist`. This is much faster than `levenshtein_distance`
+/// when most comparisons only need to know whether 2 strings are within a small distance of each other.
+///
+/// ## Example
+/// ```
+/// use string_distance::levenshtein_distance_bounded;
+///
+/// assert_eq!(levenshtein_distance_bounded("jones", "johnson", 4), Some(4));
+/// assert_eq!(levenshtein_distance_bounded("jones", "johnson", 3), None);
+/// ```
+///
+/// ## Complexity
+/// - Time:  O(min(|str1.len|, |str2.len|) * max_dist)
+/// - Space: O(min(|str1.len|, |str2.len|))
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `max_dist` - The largest distance of interest
+/// * -> The Levenshtein Distance if it is <= `max_dist`, otherwise `None`
+pub fn levenshtein_distance_bounded(str1:&str, str2:&str, max_dist:usize) -> Option<usize>
+{
+    banded_rows(str1, str2, max_dist, false)
+}
+
+/// # Bounded Optimal String Alignment Distance
+/// Calculates the OSA Distance, but only if it is no larger than `max_dist`.
+/// See `levenshtein_distance_bounded` for how the calculation is bounded.
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `max_dist` - The largest distance of interest
+/// * -> The OSA Distance if it is <= `max_dist`, otherwise `None`
+pub fn osa_distance_bounded(str1:&str, str2:&str, max_dist:usize) -> Option<usize>
+{
+    banded_rows(str1, str2, max_dist, true)
+}
+
+/// # Bounded Damerau Levenshtein Distance
+/// Calculates the Damerau Levenshtein Distance, but only if it is no larger than `max_dist`.
+/// See `levenshtein_distance_bounded` for how the calculation is bounded. As transpositions may reach back to
+/// any earlier row, the whole matrix is allocated, but only the band is calculated.
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `max_dist` - The largest distance of interest
+/// * -> The Damerau Levenshtein Distance if it is <= `max_dist`, otherwise `None`
+pub fn damerau_levenshtein_distance_bounded(str1:&str, str2:&str, max_dist:usize) -> Option<usize>
+{
+    let s1:Vec<char> = str1.chars().collect();
+    let s2:Vec<char> = str2.chars().collect();
+    let (len1, len2) = (s1.len(), s2.len());
+    if len1.abs_diff(len2) > max_dist { return None; }
+    // no distance exceeds the longer length, which keeps `max_dist + 1` from overflowing
+    let max_dist = min(max_dist, max(len1, len2));
+
+    // every value above max_dist is capped to `inf`, which also serves as the sentinel of row and column -1
+    let inf = max_dist + 1;
+    let mut m:DistMatrix<usize> = DistMatrix::new(-1..len1 as i32 + 1, -1..len2 as i32 + 1, inf);
+    for i1 in 0..len1+1 { m[(i1, 0)] = min(i1, inf); }
+    for i2 in 0..len2+1 { m[(0, i2)] = min(i2, inf); }
+
+    let mut chars: HashMap<char, usize> = HashMap::new();
+
+    for i1 in 0..len1
+    {
+        let ch1 = s1[i1];
+        // the band of str2 indices within max_dist of the diagonal
+        let lo = (i1+1).saturating_sub(max_dist+1);
+        let hi = min(len2, i1+1+max_dist);
+
+        // transpositions that skip more than max_dist characters can never be within the bound,
+        // so the last match (db) only needs to be searched for from max_dist before the band
+        let mut db:i32 = (lo.saturating_sub(max_dist)..lo).rev()
+            .find(|&i2| s2[i2] == ch1)
+            .map_or(-1, |i2| i2 as i32);
+
+        let mut row_min = m[(i1+1, 0)];
+        for (i2, &ch2) in s2.iter().enumerate().take(hi).skip(lo)
+        {
+            let k:i32 = chars.get(&ch2).map_or(-1, |v| *v as i32);
+            let l = db;
+
+            let mut cost = 1;
+            if ch1 == ch2 {
+                cost = 0;
+                db = i2 as i32;
+            }
+
+            let score = {
+                let subst = m[(i1, i2)] + cost;
+                let insert = m[(i1, i2+1)] + 1;
+                let del = m[(i1+1, i2)] + 1;
+                let transp = m[(k,l)] + ((i1 as i32) - k - 1) as usize + ((i2 as i32) - l - 1) as usize + 1;
+
+                min!(subst, insert, del, transp, inf)
+            };
+
+            m[(i1+1,i2+1)] = score;
+            row_min = min(row_min, score);
+        }
+
+        if row_min > max_dist { return None; }
+        chars.insert(ch1, i1);
+    }
+
+    let d = *m.get_last();
+    if d <= max_dist { Some(d) } else { None }
+}
+
+/// Fills the band of a Levenshtein (or OSA, when `transpositions` is set) matrix using rolling rows,
+/// 2 rows are kept for Levenshtein and 3 for OSA. The rows are over the shorter string, as both distances are symmetric.
+fn banded_rows(str1:&str, str2:&str, max_dist:usize, transpositions:bool) -> Option<usize>
+{
+    let mut s1:Vec<char> = str1.chars().collect();
+    let mut s2:Vec<char> = str2.chars().collect();
+    if s2.len() > s1.len() { swap(&mut s1, &mut s2); }
+    let (len1, len2) = (s1.len() as i32, s2.len() as i32);
+    if (len1 - len2) as usize > max_dist { return None; }
+    // no distance exceeds the longer length, which keeps `max_dist + 1` from overflowing
+    let max_dist = min(max_dist, len1 as usize);
+
+    // every value above max_dist is capped to `inf`, cells outside of the band are left as `inf`
+    let inf = max_dist + 1;
+    let band = max_dist as i32;
+    let rows = if transpositions {3} else {2};
+    let mut m:CompactDistMatrix<usize> = CompactDistMatrix::new(-1..len1, -1..len2, rows, inf);
+    for i2 in -1..len2 { m[(-1, i2)] = min((i2+1) as usize, inf); }
+
+    for i1 in 0..len1
+    {
+        let lo = max(0, i1-band);
+        let hi = min(len2-1, i1+band);
+
+        m[(i1, -1)] = min((i1+1) as usize, inf);
+        if lo > 0 { m[(i1, lo-1)] = inf; }
+        if hi < len2-1 { m[(i1, hi+1)] = inf; }
+
+        let mut row_min = m[(i1, -1)];
+        for i2 in lo..hi+1
+        {
+            let ch1 = s1[i1 as usize];
+            let ch2 = s2[i2 as usize];
+            let cost = if ch1 == ch2 {0} else {1};
+
+            let mut score = min!(m[(i1-1, i2-1)] + cost, m[(i1-1, i2)] + 1, m[(i1, i2-1)] + 1);
+            if transpositions && i1 > 0 && i2 > 0 && ch1 == s2[(i2-1) as usize] && s1[(i1-1) as usize] == ch2 {
+                score = min(score, m[(i1-2, i2-2)] + cost);
+            }
+
+            m[(i1, i2)] = min(score, inf);
+            row_min = min(row_min, m[(i1, i2)]);
+        }
+
+        if row_min > max_dist { return None; }
+    }
+
+    let d = *m.get_last();
+    if d <= max_dist { Some(d) } else { None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::levenshtein::{levenshtein_distance, osa_distance, damerau_levenshtein_distance};
+
+    static PAIRS:[(&str, &str); 10] = [
+        ("", ""), ("", "abc"), ("abc", ""), ("jones", "johnson"), ("paul", "pual"),
+        ("Paul Jones", "Jones, Paul"), ("ca", "abc"), ("failuree", "faluiere"),
+        ("abcdefghij", "badcfehgji"), ("kitten", "sitting")
+    ];
+
+    fn expected(d:usize, max:usize) -> Option<usize> {
+        if d <= max { Some(d) } else { None }
+    }
+
+    #[test]
+    fn bounded_matches_unbounded() {
+        for &(s1, s2) in PAIRS.iter() {
+            for max in 0..13 {
+                assert_eq!(levenshtein_distance_bounded(s1, s2, max), expected(levenshtein_distance(s1, s2), max), "{} -> {} <= {}", s1, s2, max);
+                assert_eq!(osa_distance_bounded(s1, s2, max), expected(osa_distance(s1, s2), max), "{} -> {} <= {}", s1, s2, max);
+                assert_eq!(damerau_levenshtein_distance_bounded(s1, s2, max), expected(damerau_levenshtein_distance(s1, s2), max), "{} -> {} <= {}", s1, s2, max);
+            }
+        }
+    }
+
+    #[test]
+    fn length_difference_exceeds_bound() {
+        assert_eq!(levenshtein_distance_bounded("a", "abcdef", 4), None);
+        assert_eq!(damerau_levenshtein_distance_bounded("abcdef", "", 5), None);
+    }
+
+    #[test]
+    fn zero_bound() {
+        assert_eq!(osa_distance_bounded("paul", "paul", 0), Some(0));
+        assert_eq!(osa_distance_bounded("paul", "pual", 0), None);
+        assert_eq!(osa_distance_bounded("paul", "pual", 1), Some(1));
+    }
+
+    #[test]
+    fn unbounded() {
+        for &(s1, s2) in PAIRS.iter() {
+            assert_eq!(levenshtein_distance_bounded(s1, s2, usize::MAX), Some(levenshtein_distance(s1, s2)));
+            assert_eq!(osa_distance_bounded(s1, s2, usize::MAX), Some(osa_distance(s1, s2)));
+            assert_eq!(damerau_levenshtein_distance_bounded(s1, s2, usize::MAX), Some(damerau_levenshtein_distance(s1, s2)));
+        }
+    }
+}
@@ -0,0 +1,70 @@
+/// # Cost Model
+/// Supplies the cost of each edit operation for the weighted edit distances
+/// (`weighted_levenshtein_distance`, `weighted_osa_distance`, `weighted_damerau_levenshtein_distance`).
+/// Costs may depend on the characters involved, e.g. substituting neighbouring keyboard keys or
+/// characters commonly confused by OCR may be cheaper than other substitutions.
+///
+/// Every method defaults to the unit cost of the plain Levenshtein family, so a model only
+/// needs to override the operations it weights differently.
+///
+/// ## Requirements
+/// * Costs must be non-negative.
+/// * `substitute_cost(ch, ch)` should be 0.0, or identical strings will have a non-zero distance.
+/// * Damerau Levenshtein assumes `2 * transpose_cost >= insert_cost + delete_cost`, as in Lowrance & Wagner.
+pub trait CostModel
+{
+    /// The cost of inserting `ch` into the first string.
+    fn insert_cost(&self, _ch:char) -> f64 { 1.0 }
+
+    /// The cost of deleting `ch` from the first string.
+    fn delete_cost(&self, _ch:char) -> f64 { 1.0 }
+
+    /// The cost of replacing `from` in the first string with `to`.
+    fn substitute_cost(&self, from:char, to:char) -> f64 {
+        if from == to {0.0} else {1.0}
+    }
+
+    /// The cost of swapping the adjacent characters `first` and `second` of the first string.
+    fn transpose_cost(&self, _first:char, _second:char) -> f64 { 1.0 }
+}
+
+/// # Operation Costs
+/// A cost model with a fixed cost per operation, regardless of the characters involved.
+/// The default is the unit cost model of the plain Levenshtein family.
+///
+/// ## Example
+/// ```
+/// use string_distance::{OperationCosts, weighted_levenshtein_distance};
+///
+/// // changes in string length are punished more than replacements
+/// let costs = OperationCosts { substitute: 0.5, ..OperationCosts::default() };
+/// assert_eq!(weighted_levenshtein_distance("paul", "pual", &costs), 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OperationCosts
+{
+    pub insert:f64,
+    pub delete:f64,
+    pub substitute:f64,
+    pub transpose:f64
+}
+
+impl Default for OperationCosts
+{
+    fn default() -> OperationCosts {
+        OperationCosts { insert:1.0, delete:1.0, substitute:1.0, transpose:1.0 }
+    }
+}
+
+impl CostModel for OperationCosts
+{
+    fn insert_cost(&self, _ch:char) -> f64 { self.insert }
+
+    fn delete_cost(&self, _ch:char) -> f64 { self.delete }
+
+    fn substitute_cost(&self, from:char, to:char) -> f64 {
+        if from == to {0.0} else {self.substitute}
+    }
+
+    fn transpose_cost(&self, _first:char, _second:char) -> f64 { self.transpose }
+}
@@ -1,4 +1,3 @@
-use std::mem::replace;
 use std::ops::{Range, Index, IndexMut};
 use std::clone::Clone;
 use std::vec::Vec;
@@ -26,10 +25,8 @@ pub struct DistMatrix<T>
 impl<T> DistMatrix<T> where T: Clone + Copy + Display
 {
     pub fn new(rx:Rng, ry:Rng, default_val:T) -> DistMatrix<T> {
-        let rx = rx;
-        let ry = ry;
-        let w = (rx.start - rx.end).abs() as usize;
-        let h = (ry.start - ry.end).abs() as usize;
+        let w = (rx.start - rx.end).unsigned_abs() as usize;
+        let h = (ry.start - ry.end).unsigned_abs() as usize;
         DistMatrix { 
             rangex:rx,
             rangey:ry,
@@ -37,7 +34,7 @@ impl<T> DistMatrix<T> where T: Clone + Copy + Display
         }
     }
 
-    pub fn fill(&mut self, rx:&Rng, ry:&Rng, gen:&mut Iterator<Item=T>)
+    pub fn fill(&mut self, rx:&Rng, ry:&Rng, gen:&mut dyn Iterator<Item=T>)
     {
         for x in rx.clone()
         {
@@ -46,7 +43,7 @@ impl<T> DistMatrix<T> where T: Clone + Copy + Display
                 let dx = x - self.rangex.start;
                 let dy = y - self.rangey.start;
                 if let Some(val) = gen.next() {
-                    replace(&mut self.m[dy as usize][dx as usize], val);
+                    self.m[dy as usize][dx as usize] = val;
                 } else {
                     return;
                 }
@@ -99,14 +96,14 @@ impl<T> Index<(i32, i32)> for DistMatrix<T> {
     }
 }
 impl<T> IndexMut<(usize, usize)> for DistMatrix<T> {
-    fn index_mut<'a>(&'a mut self, index: (usize, usize)) -> &'a mut T{
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T{
         let dx = ((index.0 as i32) - self.rangex.start) as usize;
         let dy = ((index.1 as i32) - self.rangey.start) as usize;
         self.m[dy].get_mut(dx).unwrap()
     }
 }
 impl<T> IndexMut<(i32, i32)> for DistMatrix<T> {
-    fn index_mut<'a>(&'a mut self, index: (i32, i32)) -> &'a mut T{
+    fn index_mut(&mut self, index: (i32, i32)) -> &mut T{
         let dx = (index.0-self.rangex.start) as usize;
         let dy = (index.1-self.rangey.start) as usize;
         self.m[dy].get_mut(dx).unwrap()
@@ -118,7 +115,6 @@ impl<T> Debug for DistMatrix<T> where T: Clone + Copy + Display{
 
     fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
 
-        let out_str = String::new();
         let o = self.rangey.clone()
             .map(|i2| { format!("{}\n", self.row_to_string(i2)) } )
             .collect::<String>();
@@ -127,6 +123,113 @@ impl<T> Debug for DistMatrix<T> where T: Clone + Copy + Display{
     }
 }
 
+/// # Compact Distance Matrix
+/// A rolling-row variant of `DistMatrix`, with the same ranges and indexing, that only stores the last
+/// `rows` values of x. Row x shares its storage with row x-`rows`, so writing a row replaces an older one.
+/// This suits algorithms that fill the matrix one x at a time and only look back a fixed number of rows,
+/// e.g. 2 rows for Levenshtein and 3 for OSA, cutting the memory from O(width * height) to O(rows * height).
+///
+/// Reading a row that has been rolled out returns the values of whichever row replaced it.
+///
+/// ## Example
+/// ```
+/// use string_distance::CompactDistMatrix;
+///
+/// let mut m:CompactDistMatrix<i32> = CompactDistMatrix::new(-1..10, -1..3, 2, 0);
+/// m[(-1, 0)] = 1;
+/// m[(0, 0)] = 2;
+/// m[(1, 0)] = 3;
+/// assert_eq!(m[(0, 0)], 2);
+/// assert_eq!(m[(-1, 0)], 3);
+/// ```
+pub struct CompactDistMatrix<T>
+{
+    pub rangex:Rng,
+    pub rangey:Rng,
+    m:Vec<Vec<T>>
+}
+
+impl<T> CompactDistMatrix<T> where T: Clone + Copy + Display
+{
+    pub fn new(rx:Rng, ry:Rng, rows:usize, default_val:T) -> CompactDistMatrix<T> {
+        let h = (ry.start - ry.end).unsigned_abs() as usize;
+        CompactDistMatrix {
+            rangex:rx,
+            rangey:ry,
+            m:vec![vec![default_val; h]; rows]
+        }
+    }
+
+    pub fn fill(&mut self, rx:&Rng, ry:&Rng, gen:&mut dyn Iterator<Item=T>)
+    {
+        for x in rx.clone()
+        {
+            for y in ry.clone()
+            {
+                if let Some(val) = gen.next() {
+                    self[(x, y)] = val;
+                } else {
+                    return;
+                }
+            }
+        }
+    }
+
+    pub fn width(&self) -> i32{
+        (self.rangex.start - self.rangex.end).abs()
+    }
+
+    pub fn height(&self) -> i32{
+        (self.rangey.start - self.rangey.end).abs()
+    }
+
+    /// The number of rows stored.
+    pub fn rows(&self) -> usize {
+        self.m.len()
+    }
+
+    pub fn get_last(&self) -> &T{
+        &self[(self.rangex.end-1, self.rangey.end-1)]
+    }
+}
+
+impl<T> CompactDistMatrix<T>
+{
+    /// The row slot and column of (x, y), row x is stored in slot x modulo the number of rows.
+    fn offsets(&self, x:i32, y:i32) -> (usize, usize) {
+        let dx = (x - self.rangex.start) as usize % self.m.len();
+        let dy = (y - self.rangey.start) as usize;
+        (dx, dy)
+    }
+}
+
+impl<T> Index<(usize, usize)> for CompactDistMatrix<T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &T  {
+        &self[(index.0 as i32, index.1 as i32)]
+    }
+}
+impl<T> Index<(i32, i32)> for CompactDistMatrix<T> {
+    type Output = T;
+
+    fn index(&self, index: (i32, i32)) -> &T  {
+        let (dx, dy) = self.offsets(index.0, index.1);
+        &self.m[dx][dy]
+    }
+}
+impl<T> IndexMut<(usize, usize)> for CompactDistMatrix<T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T{
+        &mut self[(index.0 as i32, index.1 as i32)]
+    }
+}
+impl<T> IndexMut<(i32, i32)> for CompactDistMatrix<T> {
+    fn index_mut(&mut self, index: (i32, i32)) -> &mut T{
+        let (dx, dy) = self.offsets(index.0, index.1);
+        &mut self.m[dx][dy]
+    }
+}
+
 #[cfg(test)]
 mod dist_matrix_tests
 {
@@ -175,4 +278,38 @@ mod dist_matrix_tests
         assert_eq!(d[(0,1)], 1);
         assert_eq!(d[(1,1)], 0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn compact_construction() {
+        let d:CompactDistMatrix<i32> = CompactDistMatrix::new(-1..100, -1..5, 3, 0);
+        assert_eq!(d.width(), 101);
+        assert_eq!(d.height(), 6);
+        assert_eq!(d.rows(), 3);
+    }
+
+    #[test]
+    fn compact_rolls_rows() {
+        let mut d:CompactDistMatrix<i32> = CompactDistMatrix::new(0..4, 0..2, 2, 0);
+        d.fill(&(0..4), &(0..2), &mut (0..));
+
+        assert_eq!(d[(2,0)], 4);
+        assert_eq!(d[(2,1)], 5);
+        assert_eq!(d[(3,0)], 6);
+        assert_eq!(*d.get_last(), 7);
+        assert_eq!(d[(0,0)], 4);
+    }
+
+    #[test]
+    fn compact_matches_full() {
+        let mut full:DistMatrix<usize> = DistMatrix::new(-1..6, -1..4, 0);
+        let mut compact:CompactDistMatrix<usize> = CompactDistMatrix::new(-1..6, -1..4, 2, 0);
+        for x in -1..6 {
+            for y in -1..4 {
+                let val = if x < 0 || y < 0 { (x + y + 2) as usize } else { full[(x-1, y)] + full[(x, y-1)] };
+                full[(x, y)] = val;
+                compact[(x, y)] = if x < 0 || y < 0 { val } else { compact[(x-1, y)] + compact[(x, y-1)] };
+            }
+        }
+        assert_eq!(full.get_last(), compact.get_last());
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt::{Display, Formatter, Result};
+
+/// # Edit Kind
+/// The kind of a single step in an edit script.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EditKind
+{
+    /// The source and target characters are equal, no edit is made.
+    Match,
+    /// The source character is replaced by the target character.
+    Substitute,
+    /// The target character is inserted into the source.
+    Insert,
+    /// The source character is deleted.
+    Delete,
+    /// 2 source characters are swapped to produce the target characters.
+    Transpose
+}
+
+/// # Edit Operation
+/// A single step in an edit script, turning the source (`str1`) into the target (`str2`).
+/// Positions are char offsets, not byte offsets.
+///
+/// * `Match`, `Substitute` - `src` and `tgt` are the positions of the compared characters.
+/// * `Insert` - `tgt` is the inserted character, `src` is the source position it is inserted before.
+/// * `Delete` - `src` is the deleted character, `tgt` is the target position it would have preceded.
+/// * `Transpose` - `src` and `tgt` are the positions of the first character of the swapped pair.
+///   For Damerau Levenshtein the second characters of the pair may follow further `Delete` and `Insert` steps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EditOp
+{
+    pub kind:EditKind,
+    pub src:usize,
+    pub tgt:usize
+}
+
+impl EditOp
+{
+    pub fn new(kind:EditKind, src:usize, tgt:usize) -> EditOp {
+        EditOp { kind, src, tgt }
+    }
+
+    /// Whether this step adds to the distance, i.e. is not a `Match`.
+    pub fn is_edit(&self) -> bool {
+        self.kind != EditKind::Match
+    }
+}
+
+impl Display for EditOp
+{
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{:?} @ src:{}, @ tgt:{}", self.kind, self.src, self.tgt)
+    }
+}
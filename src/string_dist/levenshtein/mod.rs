@@ -1,5 +1,18 @@
 mod dist_matrix;
-
+mod edit_ops;
+mod cost_model;
+mod weighted;
+mod bounded;
+mod bit_parallel;
+
+pub use self::dist_matrix::{DistMatrix, CompactDistMatrix};
+pub use self::edit_ops::{EditOp, EditKind};
+pub use self::cost_model::{CostModel, OperationCosts};
+pub use self::weighted::{weighted_levenshtein_distance, weighted_osa_distance, weighted_damerau_levenshtein_distance};
+pub use self::weighted::{Confusion, generalized_levenshtein_distance};
+pub use self::bounded::{levenshtein_distance_bounded, osa_distance_bounded, damerau_levenshtein_distance_bounded};
+
+#[allow(clippy::module_inception)]
 pub mod levenshtein
 {
     use std::iter::repeat;
@@ -7,8 +20,12 @@ pub mod levenshtein
     use std::cmp::{min, max};
     use std::collections::HashMap;
     use super::super::helpers::StringHelpers;
+    use super::super::explain::{Explain, Silent};
+    use super::super::unit::Unit;
     
     use super::dist_matrix::DistMatrix;
+    use super::edit_ops::{EditOp, EditKind};
+    use super::bit_parallel::{levenshtein_bit_parallel, osa_bit_parallel};
 
     struct CalcState<'a, T: 'a> {
         matrix:&'a DistMatrix<T>,
@@ -16,10 +33,10 @@ pub mod levenshtein
         score: i32,
         i1: i32,
         i2: i32,
-        str1: &'a str,
-        str2: &'a str
+        s1: &'a [char],
+        s2: &'a [char]
     }
-    type CalcScoreFn<T> = Fn( &CalcState<T> ) -> T;
+    type CalcScoreFn<T> = dyn Fn( &CalcState<T> ) -> T;
 
     /// # Levenshtein Distance 
     /// Calculates the string distance using the Levenshtein algorithm.
@@ -33,32 +50,59 @@ pub mod levenshtein
     /// 
     /// ## Example
     /// ```
-    /// let str1:String = String.from("anthropology");
-    /// let str2:String = String.from("anthropophagi");
-    /// let dist:u8 = levenshtein_distance(&str1, &str2);
+    /// use string_distance::levenshtein_distance;
+    /// 
+    /// let str1:String = String::from("anthropology");
+    /// let str2:String = String::from("anthropophagi");
+    /// let dist:usize = levenshtein_distance(&str1, &str2);
     /// println!("The Levenshtein Distane between {} and {} is {}", str1, str2, dist);
     /// ```
     /// 
     /// ## Complexity
-    /// - Time:  O(|str1.len|, |str2.len|)       (to fill matrix)
-    /// - Space: O(min(|str1.len|, |str2.len|))  (improvment - the algorithm can be improved to use only 2 rows)
+    /// Calculated with Myers' bit-parallel algorithm, 64 cells of the matrix at a time.
+    /// - Time:  O(⌈min(|str1.len|, |str2.len|)/64⌉ * max(|str1.len|, |str2.len|))
+    /// - Space: O(min(|str1.len|, |str2.len|))
     /// - Other Cost Models:
-    ///     insert & delete cost 1.0, replacement cost 0.5   (where change in string length is punished more)
-    ///     char-based costs
-    ///         OCR has simularities - m-n, 1-l-i
-    ///         Keyboard has closeness of keys (local specific) - a-s, s-d, q-w, w-e
-    ///         Mind may associate characters (subjective) - 6-9
-    ///         Biology such as genetic pairs - a-t
+    ///   - insert & delete cost 1.0, replacement cost 0.5   (where change in string length is punished more)
+    ///   - char-based costs
+    ///     - OCR has simularities - m-n, 1-l-i
+    ///     - Keyboard has closeness of keys (local specific) - a-s, s-d, q-w, w-e
+    ///     - Mind may associate characters (subjective) - 6-9
+    ///     - Biology such as genetic pairs - a-t
+    ///   - see `weighted_levenshtein_distance` and `CostModel`
     /// 
     /// ## Parameters
     /// * `str1` - The first string to compare
     /// * `str2` - The Second string to compare
-    /// -> The Levenshtein Distance of the passed 2 strings, 0 <= distance <= max(|str1.len|, |str2.len|)
+    /// * -> The Levenshtein Distance of the passed 2 strings, 0 <= distance <= max(|str1.len|, |str2.len|)
     pub fn levenshtein_distance(str1:&str, str2:&str) -> usize
     {
+        levenshtein_distance_by_unit(str1, str2, Unit::Chars)
+    }
+
+    /// # Levenshtein Distance (by Unit)
+    /// Identical to `levenshtein_distance`, but compares the strings in `unit`s, e.g. grapheme clusters instead of chars.
+    ///
+    /// ## Parameters
+    /// * `str1` - The first string to compare
+    /// * `str2` - The Second string to compare
+    /// * `unit` - The unit the strings are compared in
+    /// * -> The Levenshtein Distance of the passed 2 strings, in units
+    pub fn levenshtein_distance_by_unit(str1:&str, str2:&str, unit:Unit) -> usize
+    {
+        let (s1, s2) = unit.symbols(str1, str2);
+        levenshtein_bit_parallel(&s1, &s2)
+    }
+
+    /// # Levenshtein Distance (Explained)
+    /// Identical to `levenshtein_distance`, but fills the full distance matrix and reports it to `explain`.
+    pub fn levenshtein_distance_explained(str1:&str, str2:&str, explain:&mut dyn Explain) -> usize
+    {
+        let s1:Vec<char> = str1.chars().collect();
+        let s2:Vec<char> = str2.chars().collect();
         let calc_score = | state:&CalcState<usize> | { state.score as usize };
-        let matrix = build_levenshtein_matrix(str1, str2, &calc_score );
-        println!("Levenshtein Calc:\n{:?}\n", matrix);
+        let matrix = build_levenshtein_matrix(&s1, &s2, &calc_score );
+        explain.matrix("Levenshtein", &matrix);
         *matrix.get_last()
     }
     #[cfg(test)]
@@ -90,6 +134,22 @@ pub mod levenshtein
         }
     }
 
+    /// # Levenshtein Simularity
+    /// Normalizes the Levenshtein Distance to a simularity value between 0.0 and 1.0,
+    /// by dividing the distance by the length of the longest string.
+    /// 
+    /// ## Example
+    /// ```
+    /// use string_distance::levenshtein_simularity;
+    /// 
+    /// let sim:f64 = levenshtein_simularity("paul", "pual");
+    /// assert_eq!(sim, 0.5);
+    /// ```
+    /// 
+    /// ## Parameters
+    /// * `str1` - The first string to compare
+    /// * `str2` - The Second string to compare
+    /// * -> The Levenshtein Simularity of the passed 2 strings, 0.0 <= simularity <= 1.0
     pub fn levenshtein_simularity(str1:&str, str2:&str) -> f64
     {
         let m = max(str1.char_count(), str2.char_count()) as f64;
@@ -144,37 +204,64 @@ pub mod levenshtein
     /// ## Example
     /// 
     /// ```
-    /// let str1:String = String.from("anthropology");
-    /// let str2:String = String.from("anthropophagi");
-    /// let dist:u8 = osa_distance(&str1, &str2);
+    /// use string_distance::osa_distance;
+    /// 
+    /// let str1:String = String::from("anthropology");
+    /// let str2:String = String::from("anthropophagi");
+    /// let dist:usize = osa_distance(&str1, &str2);
     /// println!("The OSA Distance between {} and {} is {}", str1, str2, dist);
     /// ```
     /// 
     /// ## Complexity
-    /// - Time:  O(|str1.len|, |str2.len|)       (to fill matrix)
-    /// - Space: O(min(|str1.len|, |str2.len|))  (improvment - the algorithm can be improved to use only 2 rows)
+    /// Calculated with Hyyrö's bit-parallel algorithm, 64 cells of the matrix at a time.
+    /// - Time:  O(⌈min(|str1.len|, |str2.len|)/64⌉ * max(|str1.len|, |str2.len|))
+    /// - Space: O(min(|str1.len|, |str2.len|))
     /// 
     /// ## Parameters
     /// * `str1` - The first string to compare
     /// * `str2` - The Second string to compare
-    /// -> The OSA Distance of the passed 2 strings
-    pub fn osa_distance<'a>(str1:&'a str, str2:&'a str) -> usize
+    /// * -> The OSA Distance of the passed 2 strings
+    pub fn osa_distance(str1:&str, str2:&str) -> usize
     {
-        let calc_score = | s:&CalcState<usize> | { 
+        osa_distance_by_unit(str1, str2, Unit::Chars)
+    }
 
-            if  s.i1>0 && s.i2>0 && 
-                s.str1.nth_char(s.i1) == s.str2.nth_char(s.i2-1) &&
-                s.str1.nth_char(s.i1-1) == s.str2.nth_char(s.i2) {
-                return min(s.score as usize, s.matrix[(s.i1-2,s.i2-2)] + s.cost as usize );
-            } else {
-                return s.score as usize;
-            }
-        };
-        let matrix = build_levenshtein_matrix(str1, str2, &calc_score );
+    /// # Optimal String Alignment Distance (by Unit)
+    /// Identical to `osa_distance`, but compares the strings in `unit`s, e.g. grapheme clusters instead of chars.
+    ///
+    /// ## Parameters
+    /// * `str1` - The first string to compare
+    /// * `str2` - The Second string to compare
+    /// * `unit` - The unit the strings are compared in
+    /// * -> The OSA Distance of the passed 2 strings, in units
+    pub fn osa_distance_by_unit(str1:&str, str2:&str, unit:Unit) -> usize
+    {
+        let (s1, s2) = unit.symbols(str1, str2);
+        osa_bit_parallel(&s1, &s2)
+    }
 
-        println!("OSA Calc:\n{:?}\n", matrix);
+    /// # Optimal String Alignment Distance (Explained)
+    /// Identical to `osa_distance`, but fills the full distance matrix and reports it to `explain`.
+    pub fn osa_distance_explained(str1:&str, str2:&str, explain:&mut dyn Explain) -> usize
+    {
+        let s1:Vec<char> = str1.chars().collect();
+        let s2:Vec<char> = str2.chars().collect();
+        let matrix = build_levenshtein_matrix(&s1, &s2, &osa_calc_score );
+        explain.matrix("OSA", &matrix);
         *matrix.get_last()
     }
+
+    /// Extends the Levenshtein score with the transposition of 2 adjacent characters.
+    fn osa_calc_score(s:&CalcState<usize>) -> usize
+    {
+        if  s.i1>0 && s.i2>0 && 
+            s.s1[s.i1 as usize] == s.s2[(s.i2-1) as usize] &&
+            s.s1[(s.i1-1) as usize] == s.s2[s.i2 as usize] {
+            min(s.score as usize, s.matrix[(s.i1-2,s.i2-2)] + s.cost as usize )
+        } else {
+            s.score as usize
+        }
+    }
     #[cfg(test)]
     mod osa_distance_tests {
         use super::*;
@@ -225,9 +312,11 @@ pub mod levenshtein
     /// ## Example
     /// 
     /// ```
-    /// let str1:String = String.from("anthropology");
-    /// let str2:String = String.from("anthropophagi");
-    /// let dist:u8 = damerau_levenshtein_distance(&str1, &str2);
+    /// use string_distance::damerau_levenshtein_distance;
+    /// 
+    /// let str1:String = String::from("anthropology");
+    /// let str2:String = String::from("anthropophagi");
+    /// let dist:usize = damerau_levenshtein_distance(&str1, &str2);
     /// println!("The Damerau Levenshtein Distane between {} and {} is {}", str1, str2, dist);
     /// ```
     /// 
@@ -236,19 +325,340 @@ pub mod levenshtein
     /// ## Parameters
     /// * `str1` - The first string to compare
     /// * `str2` - The Second string to compare
-    /// -> The Damerau Levenshtein Distance of the passed 2 strings
+    /// * -> The Damerau Levenshtein Distance of the passed 2 strings
     pub fn damerau_levenshtein_distance(str1:&str, str2:&str) -> usize
     {
-        if str1 == str2 {return 0;}
+        damerau_levenshtein_distance_explained(str1, str2, &mut Silent)
+    }
 
-        let r1 = -1..(str1.chars().count()+1)as i32;
-        let r2 = -1..(str2.chars().count()+1)as i32;
+    /// # Damerau Levenshtein Distance (by Unit)
+    /// Identical to `damerau_levenshtein_distance`, but compares the strings in `unit`s, e.g. grapheme clusters instead of chars.
+    ///
+    /// ## Parameters
+    /// * `str1` - The first string to compare
+    /// * `str2` - The Second string to compare
+    /// * `unit` - The unit the strings are compared in
+    /// * -> The Damerau Levenshtein Distance of the passed 2 strings, in units
+    pub fn damerau_levenshtein_distance_by_unit(str1:&str, str2:&str, unit:Unit) -> usize
+    {
+        let (s1, s2) = unit.symbols(str1, str2);
+        damerau_levenshtein_symbols(&s1, &s2, &mut Silent)
+    }
 
-        let bounds = |r:&Range<i32>| { (r.end-r.start).abs() as usize };
+    /// # Damerau Levenshtein Distance (Explained)
+    /// Identical to `damerau_levenshtein_distance`, but reports the computed matrix to `explain`.
+    /// The matrix is not reported when either string is empty or the strings are identical, as none is built.
+    pub fn damerau_levenshtein_distance_explained(str1:&str, str2:&str, explain:&mut dyn Explain) -> usize
+    {
+        let (s1, s2) = Unit::Chars.symbols(str1, str2);
+        damerau_levenshtein_symbols(&s1, &s2, explain)
+    }
+
+    fn damerau_levenshtein_symbols(s1:&[char], s2:&[char], explain:&mut dyn Explain) -> usize
+    {
+        if s1 == s2 {return 0;}
 
         // leave if either string is empty
-        if bounds(&r1) < 2 {return bounds(&r2);}
-        if bounds(&r2) < 2 {return bounds(&r1);}
+        if s1.is_empty() {return s2.len();}
+        if s2.is_empty() {return s1.len();}
+
+        let m = build_damerau_levenshtein_matrix(s1, s2);
+        explain.matrix("Damerau Levenshtein", &m);
+        *m.get_last()
+    }
+    #[cfg(test)]
+    mod damerau_levenshtein_distance_tests {
+        use super::*;
+        
+        #[test]
+        fn empty_distance() {
+            let d = damerau_levenshtein_distance("", "");
+            assert_eq!(d, 0);
+        }
+
+        #[test]
+        fn peter_pedro_distance() {
+            let d = damerau_levenshtein_distance("jones", "johnson");
+            assert_eq!(d, 4);
+        }
+
+        #[test]
+        fn paul_pual_distance() {
+            let d = damerau_levenshtein_distance("paul", "pual");
+            assert_eq!(d, 1);
+        }
+
+        #[test]
+        fn paul_jones_distance() {
+            let d = damerau_levenshtein_distance("Paul Jones", "Jones, Paul");
+            assert_eq!(d, 11);
+        }
+    }
+
+    /// # Levenshtein Edit Script
+    /// Calculates the sequence of edit operations that turns `str1` into `str2`, by tracing back
+    /// through the Levenshtein matrix. Every operation that is not a `Match` adds 1 to the distance, so
+    /// the number of edits equals `levenshtein_distance(str1, str2)`.
+    /// 
+    /// ## Example
+    /// ```
+    /// use string_distance::{levenshtein_edit_ops, EditKind};
+    /// 
+    /// let ops = levenshtein_edit_ops("paul", "pual");
+    /// let kinds:Vec<EditKind> = ops.iter().map(|op| op.kind).collect();
+    /// assert_eq!(kinds, vec![EditKind::Match, EditKind::Substitute, EditKind::Substitute, EditKind::Match]);
+    /// ```
+    /// 
+    /// ## Parameters
+    /// * `str1` - The source string
+    /// * `str2` - The target string
+    /// * -> The edit operations in source order
+    pub fn levenshtein_edit_ops(str1:&str, str2:&str) -> Vec<EditOp>
+    {
+        let s1:Vec<char> = str1.chars().collect();
+        let s2:Vec<char> = str2.chars().collect();
+        let calc_score = | state:&CalcState<usize> | { state.score as usize };
+        let matrix = build_levenshtein_matrix(&s1, &s2, &calc_score );
+        trace_levenshtein_matrix(&s1, &s2, &matrix, false)
+    }
+
+    /// # Optimal String Alignment Edit Script
+    /// Calculates the sequence of edit operations that turns `str1` into `str2`, by tracing back
+    /// through the OSA matrix. Adjacent swapped characters are reported as a single `Transpose`.
+    /// 
+    /// ## Parameters
+    /// * `str1` - The source string
+    /// * `str2` - The target string
+    /// * -> The edit operations in source order, the number of edits equals `osa_distance(str1, str2)`
+    pub fn osa_edit_ops(str1:&str, str2:&str) -> Vec<EditOp>
+    {
+        let s1:Vec<char> = str1.chars().collect();
+        let s2:Vec<char> = str2.chars().collect();
+        let matrix = build_levenshtein_matrix(&s1, &s2, &osa_calc_score );
+        trace_levenshtein_matrix(&s1, &s2, &matrix, true)
+    }
+
+    /// # Damerau Levenshtein Edit Script
+    /// Calculates the sequence of edit operations that turns `str1` into `str2`, by tracing back
+    /// through the Damerau Levenshtein matrix. A transposition may span characters that are deleted
+    /// or inserted in between, in which case the `Transpose` is followed by those `Delete` and `Insert` steps.
+    /// 
+    /// ## Parameters
+    /// * `str1` - The source string
+    /// * `str2` - The target string
+    /// * -> The edit operations in source order, the number of edits equals `damerau_levenshtein_distance(str1, str2)`
+    pub fn damerau_levenshtein_edit_ops(str1:&str, str2:&str) -> Vec<EditOp>
+    {
+        let s1:Vec<char> = str1.chars().collect();
+        let s2:Vec<char> = str2.chars().collect();
+        let m = build_damerau_levenshtein_matrix(&s1, &s2);
+
+        // cell(i1, i2) is the distance between the prefixes ending at i1 and i2, the matrix is offset by 1
+        let cell = |i1:i32, i2:i32| { m[(i1+1, i2+1)] };
+        let last_before = |s:&[char], ch:char, end:i32| {
+            (0..end).rev().find(|&i| s[i as usize] == ch).unwrap_or(-1)
+        };
+
+        let mut ops = Vec::new();
+        let (mut i1, mut i2) = (s1.len() as i32 - 1, s2.len() as i32 - 1);
+        while i1 >= 0 || i2 >= 0
+        {
+            let score = cell(i1, i2);
+
+            if i1 >= 0 && i2 >= 0 {
+                let ch1 = s1[i1 as usize];
+                let ch2 = s2[i2 as usize];
+                let cost = if ch1 == ch2 {0} else {1};
+
+                if ch1 == ch2 && score == cell(i1-1, i2-1) {
+                    ops.push(EditOp::new(EditKind::Match, i1 as usize, i2 as usize));
+                    i1 -= 1; i2 -= 1;
+                    continue;
+                }
+
+                let k = last_before(&s1, ch2, i1);
+                let l = last_before(&s2, ch1, i2);
+                if k >= 0 && l >= 0 && score == cell(k-1, l-1) + (i1-k-1) as usize + (i2-l-1) as usize + 1 {
+                    // pushed in reverse, as the whole script is reversed at the end
+                    for i in (l+1..i2).rev() { ops.push(EditOp::new(EditKind::Insert, i1 as usize, i as usize)); }
+                    for i in (k+1..i1).rev() { ops.push(EditOp::new(EditKind::Delete, i as usize, (l+1) as usize)); }
+                    ops.push(EditOp::new(EditKind::Transpose, k as usize, l as usize));
+                    i1 = k-1; i2 = l-1;
+                    continue;
+                }
+
+                if score == cell(i1-1, i2-1) + cost {
+                    ops.push(EditOp::new(EditKind::Substitute, i1 as usize, i2 as usize));
+                    i1 -= 1; i2 -= 1;
+                    continue;
+                }
+            }
+
+            if i1 >= 0 && score == cell(i1-1, i2) + 1 {
+                ops.push(EditOp::new(EditKind::Delete, i1 as usize, (i2+1) as usize));
+                i1 -= 1;
+            } else {
+                ops.push(EditOp::new(EditKind::Insert, (i1+1) as usize, i2 as usize));
+                i2 -= 1;
+            }
+        }
+
+        ops.reverse();
+        ops
+    }
+    #[cfg(test)]
+    mod edit_ops_tests {
+        use super::*;
+
+        fn kinds(ops:&[EditOp]) -> Vec<EditKind> {
+            ops.iter().map(|op| op.kind).collect()
+        }
+
+        fn edits(ops:&[EditOp]) -> usize {
+            ops.iter().filter(|op| op.is_edit()).count()
+        }
+
+        static PAIRS:[(&str, &str); 7] = [
+            ("", ""), ("", "abc"), ("abc", ""), ("jones", "johnson"), ("paul", "pual"),
+            ("Paul Jones", "Jones, Paul"), ("ca", "abc")
+        ];
+
+        #[test]
+        fn edits_equal_distance() {
+            for &(s1, s2) in PAIRS.iter() {
+                assert_eq!(edits(&levenshtein_edit_ops(s1, s2)), levenshtein_distance(s1, s2), "{} -> {}", s1, s2);
+                assert_eq!(edits(&osa_edit_ops(s1, s2)), osa_distance(s1, s2), "{} -> {}", s1, s2);
+                assert_eq!(edits(&damerau_levenshtein_edit_ops(s1, s2)), damerau_levenshtein_distance(s1, s2), "{} -> {}", s1, s2);
+            }
+        }
+
+        #[test]
+        fn empty_edit_ops() {
+            assert!(levenshtein_edit_ops("", "").is_empty());
+            assert_eq!(kinds(&osa_edit_ops("ab", "")), vec![EditKind::Delete, EditKind::Delete]);
+            assert_eq!(damerau_levenshtein_edit_ops("", "ab"), vec![
+                EditOp::new(EditKind::Insert, 0, 0),
+                EditOp::new(EditKind::Insert, 0, 1)
+            ]);
+        }
+
+        #[test]
+        fn jones_johnson_edit_ops() {
+            assert_eq!(kinds(&levenshtein_edit_ops("jones", "johnson")), vec![
+                EditKind::Match, EditKind::Match, EditKind::Insert, EditKind::Match,
+                EditKind::Insert, EditKind::Substitute, EditKind::Substitute
+            ]);
+        }
+
+        #[test]
+        fn paul_pual_transposition() {
+            assert_eq!(osa_edit_ops("paul", "pual"), vec![
+                EditOp::new(EditKind::Match, 0, 0),
+                EditOp::new(EditKind::Transpose, 1, 1),
+                EditOp::new(EditKind::Match, 3, 3)
+            ]);
+            assert_eq!(damerau_levenshtein_edit_ops("paul", "pual"), osa_edit_ops("paul", "pual"));
+        }
+
+        #[test]
+        fn ca_abc_transposition_with_insert() {
+            assert_eq!(damerau_levenshtein_edit_ops("ca", "abc"), vec![
+                EditOp::new(EditKind::Transpose, 0, 0),
+                EditOp::new(EditKind::Insert, 1, 1)
+            ]);
+        }
+    }
+
+    #[cfg(test)]
+    mod by_unit_tests {
+        use super::*;
+
+        static FAMILY:&str = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+
+        #[test]
+        fn chars_equal_plain_distance() {
+            assert_eq!(levenshtein_distance_by_unit("jones", "johnson", Unit::Chars), 4);
+            assert_eq!(osa_distance_by_unit("paul", "pual", Unit::Chars), 1);
+            assert_eq!(damerau_levenshtein_distance_by_unit("ca", "abc", Unit::Chars), 2);
+        }
+
+        #[test]
+        fn grapheme_distance() {
+            let with_family = format!("hi {}", FAMILY);
+            assert_eq!(levenshtein_distance(&with_family, "hi "), 5);
+            assert_eq!(levenshtein_distance_by_unit(&with_family, "hi ", Unit::Graphemes), 1);
+            assert_eq!(osa_distance_by_unit("e\u{301}a", "ae\u{301}", Unit::Graphemes), 1);
+            assert_eq!(damerau_levenshtein_distance_by_unit("e\u{301}a", "ae\u{301}", Unit::Graphemes), 1);
+        }
+
+        #[test]
+        fn byte_distance() {
+            assert_eq!(levenshtein_distance_by_unit("é", "e", Unit::Bytes), 2);
+        }
+
+        #[test]
+        fn word_distance() {
+            assert_eq!(osa_distance_by_unit("Jones, Paul", "Paul Jones", Unit::Words), 1);
+            assert_eq!(damerau_levenshtein_distance_by_unit("Paul  Jones", "Jones Paul", Unit::Words), 1);
+            assert_eq!(levenshtein_distance_by_unit("...", "", Unit::Words), 0);
+        }
+    }
+
+    /// Traces back through a matrix built by `build_levenshtein_matrix`, from the last cell to the first.
+    fn trace_levenshtein_matrix(s1:&[char], s2:&[char], m:&DistMatrix<usize>, transpositions:bool) -> Vec<EditOp>
+    {
+        let mut ops = Vec::new();
+        let (mut i1, mut i2) = (s1.len() as i32 - 1, s2.len() as i32 - 1);
+        while i1 >= 0 || i2 >= 0
+        {
+            let score = m[(i1, i2)];
+
+            if i1 >= 0 && i2 >= 0 {
+                let ch1 = s1[i1 as usize];
+                let ch2 = s2[i2 as usize];
+
+                if ch1 == ch2 && score == m[(i1-1, i2-1)] {
+                    ops.push(EditOp::new(EditKind::Match, i1 as usize, i2 as usize));
+                    i1 -= 1; i2 -= 1;
+                    continue;
+                }
+
+                if transpositions && i1 > 0 && i2 > 0 &&
+                    ch1 == s2[(i2-1) as usize] && s1[(i1-1) as usize] == ch2 &&
+                    score == m[(i1-2, i2-2)] + 1 {
+                    ops.push(EditOp::new(EditKind::Transpose, (i1-1) as usize, (i2-1) as usize));
+                    i1 -= 2; i2 -= 2;
+                    continue;
+                }
+
+                if score == m[(i1-1, i2-1)] + 1 {
+                    ops.push(EditOp::new(EditKind::Substitute, i1 as usize, i2 as usize));
+                    i1 -= 1; i2 -= 1;
+                    continue;
+                }
+            }
+
+            if i1 >= 0 && score == m[(i1-1, i2)] + 1 {
+                ops.push(EditOp::new(EditKind::Delete, i1 as usize, (i2+1) as usize));
+                i1 -= 1;
+            } else {
+                ops.push(EditOp::new(EditKind::Insert, (i1+1) as usize, i2 as usize));
+                i2 -= 1;
+            }
+        }
+
+        ops.reverse();
+        ops
+    }
+
+    /// Builds the Damerau Levenshtein matrix, offset by 1 so that row and column -1 hold the
+    /// `max_dist` sentinel used for transpositions with no earlier match.
+    fn build_damerau_levenshtein_matrix(s1:&[char], s2:&[char]) -> DistMatrix<usize>
+    {
+        let r1 = -1..(s1.len()+1)as i32;
+        let r2 = -1..(s2.len()+1)as i32;
+
+        let bounds = |r:&Range<i32>| { (r.end-r.start).unsigned_abs() as usize };
 
         let mut m:DistMatrix<usize> = DistMatrix::new(r1.clone(), r2.clone() ,0);
         let max_dist = bounds(&r1) + bounds(&r2) - 4;
@@ -260,11 +670,11 @@ pub mod levenshtein
         
         let mut chars: HashMap<char, usize> = HashMap::new();
         
-        for (i1, ch1) in str1.chars().enumerate()
+        for (i1, &ch1) in s1.iter().enumerate()
         {
             let mut db:i32 = -1;
 
-            for (i2, ch2) in str2.chars().enumerate()
+            for (i2, &ch2) in s2.iter().enumerate()
             {
                 let k:i32 = if let Some(v) = chars.get(&ch2) {*v as i32} else{-1};
                 let l = db;
@@ -287,56 +697,27 @@ pub mod levenshtein
                 m[(i1+1,i2+1)] = score;
             }
 
-            chars.insert(str1.nth_char(i1 as i32), i1);
+            chars.insert(ch1, i1);
         }
 
-        println!("{:?}", m);
-        *m.get_last()
-    }
-    #[cfg(test)]
-    mod damerau_levenshtein_distance_tests {
-        use super::*;
-        
-        #[test]
-        fn empty_distance() {
-            let d = damerau_levenshtein_distance("", "");
-            assert_eq!(d, 0);
-        }
-
-        #[test]
-        fn peter_pedro_distance() {
-            let d = damerau_levenshtein_distance("jones", "johnson");
-            assert_eq!(d, 4);
-        }
-
-        #[test]
-        fn paul_pual_distance() {
-            let d = damerau_levenshtein_distance("paul", "pual");
-            assert_eq!(d, 1);
-        }
-
-        #[test]
-        fn paul_jones_distance() {
-            let d = damerau_levenshtein_distance("Paul Jones", "Jones, Paul");
-            assert_eq!(d, 11);
-        }
+        m
     }
 
 
-    fn build_levenshtein_matrix<'a>(str1:&'a str, str2:&'a str, calc_score: &'a CalcScoreFn<usize>) -> DistMatrix<usize>{
-        let mut m:DistMatrix<usize> = DistMatrix::new(-1..str1.char_count(), -1..str2.char_count(), 0);
-        m.fill( &(-1..0), &(-1..str2.char_count()), &mut (0..));
-        m.fill( &(-1..str1.char_count()), &(-1..0), &mut (0..));
+    /// Builds the Levenshtein matrix of 2 pre-decoded strings, so every cell is calculated in constant time.
+    fn build_levenshtein_matrix<'a>(s1:&'a [char], s2:&'a [char], calc_score: &'a CalcScoreFn<usize>) -> DistMatrix<usize>{
+        let (len1, len2) = (s1.len() as i32, s2.len() as i32);
+        let mut m:DistMatrix<usize> = DistMatrix::new(-1..len1, -1..len2, 0);
+        m.fill( &(-1..0), &(-1..len2), &mut (0..));
+        m.fill( &(-1..len1), &(-1..0), &mut (0..));
 
-        for i1 in 0..str1.char_count()
+        for (i1, &ch1) in (0..len1).zip(s1.iter())
         {
 
-            for i2 in 0..str2.char_count()
+            for (i2, &ch2) in (0..len2).zip(s2.iter())
             {
                 let score = {
 
-                    let ch1 = str1.nth_char(i1);
-                    let ch2 = str2.nth_char(i2);
                     let cost:i32 = if ch1 == ch2 {0} else {1};
                     
                     let score:i32 = {    
@@ -346,7 +727,7 @@ pub mod levenshtein
                         min!( del, insert, subst) as i32
                     };
                     
-                    let state:CalcState<usize> = CalcState { matrix:&m, cost:cost, score: score, i1:i1, i2:i2, str1:str1, str2:str2  };
+                    let state:CalcState<usize> = CalcState { matrix:&m, cost, score, i1, i2, s1, s2 };
                     calc_score(&state)
                 };
                 m[(i1,i2)] = score;
@@ -355,19 +736,4 @@ pub mod levenshtein
 
         m
     }
-
-    /*
-    Distance_Matrix
-    // use arrays of fixed length,
-    // Support common traits
-    // Support default values
-    // Support assignment operations for whole row or column with fixed or incremental values
-
-
-
-    Compact_Distance_Matrix
-    // used if poss
-    // only stores 2 rows at a time
-
-    */
-}
\ No newline at end of file
+}
@@ -0,0 +1,350 @@
+use std::f64;
+use std::collections::HashMap;
+use super::dist_matrix::{DistMatrix, CompactDistMatrix};
+use super::cost_model::CostModel;
+
+/// # Weighted Levenshtein Distance
+/// Calculates the Levenshtein Distance, where the cost of each deletion, insertion and substitution
+/// is supplied by a `CostModel`. With `OperationCosts::default()` the result equals `levenshtein_distance`.
+///
+/// ## Example
+/// ```
+/// use string_distance::{OperationCosts, weighted_levenshtein_distance};
+///
+/// let costs = OperationCosts { insert: 2.0, delete: 2.0, ..OperationCosts::default() };
+/// assert_eq!(weighted_levenshtein_distance("jones", "johnson", &costs), 6.0);
+/// ```
+///
+/// ## Complexity
+/// - Time:  O(|str1.len| * |str2.len|)
+/// - Space: O(min(|str1.len|, |str2.len|))
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `costs` - The cost of each edit operation
+/// * -> The smallest total cost of the edits that turn `str1` into `str2`
+pub fn weighted_levenshtein_distance(str1:&str, str2:&str, costs:&dyn CostModel) -> f64
+{
+    weighted_rows(str1, str2, costs, false)
+}
+
+/// # Weighted Optimal String Alignment Distance
+/// Calculates the OSA Distance, where the cost of each deletion, insertion, substitution and transposition
+/// of adjacent characters is supplied by a `CostModel`. With `OperationCosts::default()` the result equals `osa_distance`.
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `costs` - The cost of each edit operation
+/// * -> The smallest total cost of the edits that turn `str1` into `str2`
+pub fn weighted_osa_distance(str1:&str, str2:&str, costs:&dyn CostModel) -> f64
+{
+    weighted_rows(str1, str2, costs, true)
+}
+
+/// # Weighted Damerau Levenshtein Distance
+/// Calculates the Damerau Levenshtein Distance, where the cost of each deletion, insertion, substitution and
+/// transposition is supplied by a `CostModel`. With `OperationCosts::default()` the result equals `damerau_levenshtein_distance`.
+///
+/// A transposition may have characters deleted or inserted between the swapped pair, each of those is charged
+/// at its own delete or insert cost. The result is only optimal when `2 * transpose_cost >= insert_cost + delete_cost`.
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `costs` - The cost of each edit operation
+/// * -> The smallest total cost of the edits that turn `str1` into `str2`
+pub fn weighted_damerau_levenshtein_distance(str1:&str, str2:&str, costs:&dyn CostModel) -> f64
+{
+    let s1:Vec<char> = str1.chars().collect();
+    let s2:Vec<char> = str2.chars().collect();
+    let (len1, len2) = (s1.len() as i32, s2.len() as i32);
+
+    // prefix sums, so the cost of the characters skipped by a transposition is found in constant time
+    let mut del_sum = vec![0.0; s1.len()+1];
+    let mut ins_sum = vec![0.0; s2.len()+1];
+    for (i, ch) in s1.iter().enumerate() { del_sum[i+1] = del_sum[i] + costs.delete_cost(*ch); }
+    for (i, ch) in s2.iter().enumerate() { ins_sum[i+1] = ins_sum[i] + costs.insert_cost(*ch); }
+
+    // offset by 1, row and column -1 are never reachable
+    let mut m:DistMatrix<f64> = DistMatrix::new(-1..len1+1, -1..len2+1, f64::INFINITY);
+    m[(0, 0)] = 0.0;
+    for i1 in 0..len1 { m[(i1+1, 0)] = del_sum[(i1+1) as usize]; }
+    for i2 in 0..len2 { m[(0, i2+1)] = ins_sum[(i2+1) as usize]; }
+
+    let mut chars: HashMap<char, i32> = HashMap::new();
+
+    for i1 in 0..len1
+    {
+        let ch1 = s1[i1 as usize];
+        let mut db:i32 = -1;
+
+        for i2 in 0..len2
+        {
+            let ch2 = s2[i2 as usize];
+            let k:i32 = *chars.get(&ch2).unwrap_or(&-1);
+            let l = db;
+            if ch1 == ch2 { db = i2; }
+
+            let subst = m[(i1, i2)] + costs.substitute_cost(ch1, ch2);
+            let insert = m[(i1+1, i2)] + costs.insert_cost(ch2);
+            let del = m[(i1, i2+1)] + costs.delete_cost(ch1);
+            let transp = if k >= 0 && l >= 0 {
+                m[(k, l)]
+                    + (del_sum[i1 as usize] - del_sum[(k+1) as usize])
+                    + costs.transpose_cost(s1[k as usize], ch1)
+                    + (ins_sum[i2 as usize] - ins_sum[(l+1) as usize])
+            } else {
+                f64::INFINITY
+            };
+
+            m[(i1+1, i2+1)] = subst.min(insert).min(del).min(transp);
+        }
+
+        chars.insert(ch1, i1);
+    }
+
+    *m.get_last()
+}
+
+/// # Confusion
+/// A rule that substitutes the substring `from` with the substring `to` for `cost`, used by
+/// `generalized_levenshtein_distance` to charge multi-character confusions (e.g. `rn` read as `m`) as a single edit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Confusion
+{
+    pub from:Vec<char>,
+    pub to:Vec<char>,
+    pub cost:f64
+}
+
+impl Confusion
+{
+    pub fn new(from:&str, to:&str, cost:f64) -> Confusion {
+        Confusion { from: from.chars().collect(), to: to.chars().collect(), cost }
+    }
+}
+
+/// # Generalized Levenshtein Distance
+/// Calculates the weighted Levenshtein Distance, where besides single character edits any `Confusion`
+/// may substitute a whole substring. Confusions are directional, add the reverse rule for symmetric confusions.
+///
+/// ## Example
+/// ```
+/// use string_distance::{Confusion, OperationCosts, generalized_levenshtein_distance};
+///
+/// let rules = [Confusion::new("rn", "m", 0.25)];
+/// let d = generalized_levenshtein_distance("modern", "modem", &OperationCosts::default(), &rules);
+/// assert_eq!(d, 0.25);
+/// ```
+///
+/// ## Complexity
+/// - Time:  O(|str1.len| * |str2.len| * |confusions|)
+/// - Space: O(|longest confusion.from| * |str2.len|)
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `costs` - The cost of each single character edit operation
+/// * `confusions` - The substring substitutions allowed in addition
+/// * -> The smallest total cost of the edits that turn `str1` into `str2`
+pub fn generalized_levenshtein_distance(str1:&str, str2:&str, costs:&dyn CostModel, confusions:&[Confusion]) -> f64
+{
+    let s1:Vec<char> = str1.chars().collect();
+    let s2:Vec<char> = str2.chars().collect();
+    let (len1, len2) = (s1.len() as i32, s2.len() as i32);
+
+    // whether `part` is the substring ending at `end`, an empty part ends everywhere, including at -1
+    let ends_at = |s:&[char], end:i32, part:&[char]| {
+        let start = end + 1 - part.len() as i32;
+        start >= 0 && &s[start as usize..(end+1) as usize] == part
+    };
+
+    // a confusion looks back as many rows as its `from` is long
+    let rows = 1 + confusions.iter().map(|c| c.from.len()).max().unwrap_or(0).max(1);
+    let mut m:CompactDistMatrix<f64> = CompactDistMatrix::new(-1..len1, -1..len2, rows, 0.0);
+    for i1 in -1..len1
+    {
+        for i2 in -1..len2
+        {
+            if i1 == -1 && i2 == -1 { continue; }
+
+            let mut score = f64::INFINITY;
+            if i1 >= 0 { score = score.min(m[(i1-1, i2)] + costs.delete_cost(s1[i1 as usize])); }
+            if i2 >= 0 { score = score.min(m[(i1, i2-1)] + costs.insert_cost(s2[i2 as usize])); }
+            if i1 >= 0 && i2 >= 0 {
+                score = score.min(m[(i1-1, i2-1)] + costs.substitute_cost(s1[i1 as usize], s2[i2 as usize]));
+            }
+
+            for c in confusions.iter()
+            {
+                if (c.from.is_empty() && c.to.is_empty()) || !ends_at(&s1, i1, &c.from) || !ends_at(&s2, i2, &c.to) {
+                    continue;
+                }
+                score = score.min(m[(i1 - c.from.len() as i32, i2 - c.to.len() as i32)] + c.cost);
+            }
+
+            m[(i1, i2)] = score;
+        }
+    }
+
+    *m.get_last()
+}
+
+/// Views a cost model from the other string, so that swapping the strings keeps the distance.
+struct Reversed<'a>(&'a dyn CostModel);
+
+impl<'a> CostModel for Reversed<'a>
+{
+    fn insert_cost(&self, ch:char) -> f64 { self.0.delete_cost(ch) }
+
+    fn delete_cost(&self, ch:char) -> f64 { self.0.insert_cost(ch) }
+
+    fn substitute_cost(&self, from:char, to:char) -> f64 { self.0.substitute_cost(to, from) }
+
+    fn transpose_cost(&self, first:char, second:char) -> f64 { self.0.transpose_cost(second, first) }
+}
+
+/// Calculates the weighted Levenshtein (or OSA, when `transpositions` is set) distance using rolling rows,
+/// 2 rows are kept for Levenshtein and 3 for OSA. The rows are over the shorter string.
+fn weighted_rows(str1:&str, str2:&str, costs:&dyn CostModel, transpositions:bool) -> f64
+{
+    let s1:Vec<char> = str1.chars().collect();
+    let s2:Vec<char> = str2.chars().collect();
+    if s2.len() > s1.len() {
+        weighted_rows_of(&s2, &s1, &Reversed(costs), transpositions)
+    } else {
+        weighted_rows_of(&s1, &s2, costs, transpositions)
+    }
+}
+
+fn weighted_rows_of(s1:&[char], s2:&[char], costs:&dyn CostModel, transpositions:bool) -> f64
+{
+    let (len1, len2) = (s1.len() as i32, s2.len() as i32);
+
+    let rows = if transpositions {3} else {2};
+    let mut m:CompactDistMatrix<f64> = CompactDistMatrix::new(-1..len1, -1..len2, rows, 0.0);
+    for i2 in 0..len2 { m[(-1, i2)] = m[(-1, i2-1)] + costs.insert_cost(s2[i2 as usize]); }
+
+    for i1 in 0..len1
+    {
+        let ch1 = s1[i1 as usize];
+        m[(i1, -1)] = m[(i1-1, -1)] + costs.delete_cost(ch1);
+
+        for i2 in 0..len2
+        {
+            let ch2 = s2[i2 as usize];
+
+            let del = m[(i1-1, i2)] + costs.delete_cost(ch1);
+            let insert = m[(i1, i2-1)] + costs.insert_cost(ch2);
+            let subst = m[(i1-1, i2-1)] + costs.substitute_cost(ch1, ch2);
+            let mut score = del.min(insert).min(subst);
+
+            if transpositions && i1 > 0 && i2 > 0 {
+                let prev1 = s1[(i1-1) as usize];
+                if ch1 != prev1 && ch1 == s2[(i2-1) as usize] && prev1 == ch2 {
+                    score = score.min(m[(i1-2, i2-2)] + costs.transpose_cost(prev1, ch1));
+                }
+            }
+
+            m[(i1, i2)] = score;
+        }
+    }
+
+    *m.get_last()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::cost_model::OperationCosts;
+    use super::super::levenshtein::{levenshtein_distance, osa_distance, damerau_levenshtein_distance};
+
+    static PAIRS:[(&str, &str); 8] = [
+        ("", ""), ("", "abc"), ("abc", ""), ("jones", "johnson"), ("paul", "pual"),
+        ("Paul Jones", "Jones, Paul"), ("ca", "abc"), ("failuree", "faluiere")
+    ];
+
+    #[test]
+    fn unit_costs_equal_unweighted() {
+        let costs = OperationCosts::default();
+        for &(s1, s2) in PAIRS.iter() {
+            assert_eq!(weighted_levenshtein_distance(s1, s2, &costs), levenshtein_distance(s1, s2) as f64, "{} -> {}", s1, s2);
+            assert_eq!(weighted_osa_distance(s1, s2, &costs), osa_distance(s1, s2) as f64, "{} -> {}", s1, s2);
+            assert_eq!(weighted_damerau_levenshtein_distance(s1, s2, &costs), damerau_levenshtein_distance(s1, s2) as f64, "{} -> {}", s1, s2);
+        }
+    }
+
+    #[test]
+    fn half_cost_substitution() {
+        let costs = OperationCosts { substitute: 0.5, ..OperationCosts::default() };
+        assert_eq!(weighted_levenshtein_distance("peter", "pedro", &costs), 1.5);
+        assert_eq!(weighted_levenshtein_distance("abc", "", &costs), 3.0);
+    }
+
+    #[test]
+    fn expensive_transposition() {
+        let costs = OperationCosts { transpose: 3.0, ..OperationCosts::default() };
+        assert_eq!(weighted_osa_distance("paul", "pual", &costs), 2.0);
+        assert_eq!(weighted_damerau_levenshtein_distance("paul", "pual", &costs), 2.0);
+    }
+
+    #[test]
+    fn transposition_with_insert() {
+        let costs = OperationCosts { insert: 0.5, delete: 0.5, substitute: 2.0, transpose: 0.5 };
+        assert_eq!(weighted_damerau_levenshtein_distance("ca", "abc", &costs), 1.0);
+    }
+
+    #[test]
+    fn generalized_without_confusions() {
+        let costs = OperationCosts::default();
+        for &(s1, s2) in PAIRS.iter() {
+            assert_eq!(generalized_levenshtein_distance(s1, s2, &costs, &[]), levenshtein_distance(s1, s2) as f64, "{} -> {}", s1, s2);
+        }
+    }
+
+    #[test]
+    fn generalized_multi_char_confusions() {
+        let costs = OperationCosts::default();
+        let rules = [Confusion::new("rn", "m", 0.25), Confusion::new("m", "rn", 0.25), Confusion::new("", "-", 0.1)];
+        assert_eq!(generalized_levenshtein_distance("corner", "comer", &costs, &rules), 0.25);
+        assert_eq!(generalized_levenshtein_distance("comer", "corner", &costs, &rules), 0.25);
+        assert_eq!(generalized_levenshtein_distance("modern", "mo-dem", &costs, &rules), 0.35);
+    }
+
+    struct VowelsAreCheap;
+    impl CostModel for VowelsAreCheap {
+        fn substitute_cost(&self, from:char, to:char) -> f64 {
+            let vowel = |ch:char| "aeiou".contains(ch);
+            if from == to {0.0} else if vowel(from) && vowel(to) {0.25} else {1.0}
+        }
+    }
+
+    #[test]
+    fn char_based_costs() {
+        assert_eq!(weighted_levenshtein_distance("hello", "hallo", &VowelsAreCheap), 0.25);
+        assert_eq!(weighted_levenshtein_distance("hello", "hxllo", &VowelsAreCheap), 1.0);
+    }
+
+    #[test]
+    fn asymmetric_costs_either_order() {
+        let costs = OperationCosts { insert: 0.5, delete: 2.0, ..OperationCosts::default() };
+        assert_eq!(weighted_levenshtein_distance("abc", "abcdef", &costs), 1.5);
+        assert_eq!(weighted_levenshtein_distance("abcdef", "abc", &costs), 6.0);
+    }
+
+    struct OrderedTransposition;
+    impl CostModel for OrderedTransposition {
+        fn transpose_cost(&self, first:char, second:char) -> f64 {
+            if first < second {0.25} else {0.75}
+        }
+    }
+
+    #[test]
+    fn asymmetric_transposition_either_order() {
+        assert_eq!(weighted_osa_distance("ab", "bacd", &OrderedTransposition), 2.25);
+        assert_eq!(weighted_osa_distance("ba", "abcd", &OrderedTransposition), 2.75);
+        assert_eq!(weighted_osa_distance("abcd", "ba", &OrderedTransposition), 2.25);
+    }
+}
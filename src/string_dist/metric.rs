@@ -0,0 +1,458 @@
+use std::cmp::max;
+use super::helpers::{StringHelpers, distance_to_simularity};
+use super::hamming::hamming_distance;
+use super::jaro::{JWOptions, jaro_simularity, jaro_winkler_simularity, strcmp95_simularity};
+use super::levenshtein::levenshtein::{levenshtein_distance, osa_distance, damerau_levenshtein_distance};
+use super::subsequence;
+use super::lcs;
+use super::token::{Tokenizer, Multiplicity};
+use super::jaccard::{jaccard_simularity, sorensen_dice_simularity, overlap_coefficient, tversky_index};
+use super::cosine::cosine_simularity;
+use super::tfidf::{Corpus, tfidf_simularity};
+use super::soft_tfidf::soft_tfidf_simularity;
+
+/// # String Metric
+/// A common interface over every string distance and simularity algorithm, so that
+/// callers can hold a `&dyn StringMetric` and swap algorithms by configuration.
+///
+/// Edit-based algorithms (Hamming, Levenshtein, OSA, Damerau Levenshtein) are natively
+/// distances, Jaro and Jaro Winkler are natively simularities. Each implementation
+/// provides the other measures in terms of its native one.
+///
+/// ## Example
+/// ```
+/// use string_distance::{StringMetric, Levenshtein, JaroWinkler};
+///
+/// let metrics:Vec<Box<dyn StringMetric>> = vec![Box::new(Levenshtein), Box::new(JaroWinkler::default())];
+/// for metric in metrics.iter() {
+///     println!("{}", metric.normalized_similarity("paul", "pual"));
+/// }
+/// ```
+pub trait StringMetric
+{
+    /// The raw distance between the 2 strings. 0.0 means the strings are identical.
+    fn distance(&self, str1:&str, str2:&str) -> f64;
+
+    /// The raw simularity between the 2 strings. Larger values mean more similar strings.
+    fn similarity(&self, str1:&str, str2:&str) -> f64;
+
+    /// The distance scaled to 0.0 <= distance <= 1.0, where 0.0 means the strings are identical.
+    fn normalized_distance(&self, str1:&str, str2:&str) -> f64 {
+        1.0 - self.normalized_similarity(str1, str2)
+    }
+
+    /// The simularity scaled to 0.0 <= simularity <= 1.0, where 1.0 means the strings are identical.
+    fn normalized_similarity(&self, str1:&str, str2:&str) -> f64;
+}
+
+/// The longest possible edit distance between 2 strings, used to convert edit distances to simularities.
+fn max_edit_distance(str1:&str, str2:&str) -> f64 {
+    max(str1.char_count(), str2.char_count()) as f64
+}
+
+macro_rules! edit_distance_metric {
+    ( $name:ident, $dist_fn:ident ) => {
+        impl StringMetric for $name
+        {
+            fn distance(&self, str1:&str, str2:&str) -> f64 {
+                $dist_fn(str1, str2) as f64
+            }
+
+            fn similarity(&self, str1:&str, str2:&str) -> f64 {
+                max_edit_distance(str1, str2) - self.distance(str1, str2)
+            }
+
+            fn normalized_distance(&self, str1:&str, str2:&str) -> f64 {
+                let m = max_edit_distance(str1, str2);
+                if m == 0.0 { 0.0 } else { self.distance(str1, str2) / m }
+            }
+
+            fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+                distance_to_simularity(str1, str2, self.distance(str1, str2))
+            }
+        }
+    };
+}
+
+/// Hamming Distance as a `StringMetric`, see `hamming_distance`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Hamming;
+edit_distance_metric!(Hamming, hamming_distance);
+
+/// Levenshtein Distance as a `StringMetric`, see `levenshtein_distance`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Levenshtein;
+edit_distance_metric!(Levenshtein, levenshtein_distance);
+
+/// Optimal String Alignment Distance as a `StringMetric`, see `osa_distance`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OptimalStringAlignment;
+edit_distance_metric!(OptimalStringAlignment, osa_distance);
+
+/// Damerau Levenshtein Distance as a `StringMetric`, see `damerau_levenshtein_distance`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DamerauLevenshtein;
+edit_distance_metric!(DamerauLevenshtein, damerau_levenshtein_distance);
+
+/// Jaro Simularity as a `StringMetric`, see `jaro_simularity`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Jaro
+{
+    pub opts:Option<JWOptions>
+}
+
+/// Jaro Winkler Simularity as a `StringMetric`, see `jaro_winkler_simularity`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct JaroWinkler
+{
+    pub opts:Option<JWOptions>
+}
+
+/// strcmp95 Simularity as a `StringMetric`, see `strcmp95_simularity`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Strcmp95
+{
+    pub opts:Option<JWOptions>,
+    pub long_strings:bool
+}
+
+impl StringMetric for Jaro
+{
+    fn distance(&self, str1:&str, str2:&str) -> f64 {
+        1.0 - self.similarity(str1, str2)
+    }
+
+    fn similarity(&self, str1:&str, str2:&str) -> f64 {
+        jaro_simularity(str1, str2, self.opts)
+    }
+
+    fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+        self.similarity(str1, str2)
+    }
+}
+
+impl StringMetric for JaroWinkler
+{
+    fn distance(&self, str1:&str, str2:&str) -> f64 {
+        1.0 - self.similarity(str1, str2)
+    }
+
+    fn similarity(&self, str1:&str, str2:&str) -> f64 {
+        jaro_winkler_simularity(str1, str2, self.opts)
+    }
+
+    fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+        self.similarity(str1, str2)
+    }
+}
+
+impl StringMetric for Strcmp95
+{
+    fn distance(&self, str1:&str, str2:&str) -> f64 {
+        1.0 - self.similarity(str1, str2)
+    }
+
+    fn similarity(&self, str1:&str, str2:&str) -> f64 {
+        strcmp95_simularity(str1, str2, self.opts, self.long_strings)
+    }
+
+    fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+        self.similarity(str1, str2)
+    }
+}
+
+/// Longest Common Subsequence as a `StringMetric`. The distance is the Indel Distance, see `subsequence::distance`,
+/// and the simularity is the length of the longest common subsequence.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LongestCommonSubsequence;
+
+impl StringMetric for LongestCommonSubsequence
+{
+    fn distance(&self, str1:&str, str2:&str) -> f64 {
+        subsequence::distance(str1, str2) as f64
+    }
+
+    fn similarity(&self, str1:&str, str2:&str) -> f64 {
+        subsequence::length(str1, str2) as f64
+    }
+
+    fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+        subsequence::normalized_simularity(str1, str2)
+    }
+}
+
+/// Longest Common Substring as a `StringMetric`, see `lcs::distance`. The simularity is the length of
+/// the longest common substring.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LongestCommonSubstring;
+
+impl StringMetric for LongestCommonSubstring
+{
+    fn distance(&self, str1:&str, str2:&str) -> f64 {
+        lcs::distance(str1, str2) as f64
+    }
+
+    fn similarity(&self, str1:&str, str2:&str) -> f64 {
+        lcs::length(str1, str2) as f64
+    }
+
+    fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+        lcs::normalized_simularity(str1, str2)
+    }
+}
+
+/// Ratcliff/Obershelp Simularity as a `StringMetric`, see `ratcliff_obershelp_simularity`.
+/// The default uses difflib's autojunk heuristic, as Python does.
+#[derive(Clone, Copy, Debug)]
+pub struct RatcliffObershelp
+{
+    pub autojunk:bool
+}
+
+impl Default for RatcliffObershelp
+{
+    fn default() -> RatcliffObershelp {
+        RatcliffObershelp { autojunk: true }
+    }
+}
+
+impl StringMetric for RatcliffObershelp
+{
+    fn distance(&self, str1:&str, str2:&str) -> f64 {
+        1.0 - self.similarity(str1, str2)
+    }
+
+    fn similarity(&self, str1:&str, str2:&str) -> f64 {
+        lcs::ratcliff_obershelp_simularity(str1, str2, self.autojunk)
+    }
+
+    fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+        self.similarity(str1, str2)
+    }
+}
+
+macro_rules! token_set_metric {
+    ( $name:ident, $sim_fn:ident ) => {
+        impl<T> $name<T> where T: Tokenizer
+        {
+            pub fn new(tokenizer:T, multiplicity:Multiplicity) -> $name<T> {
+                $name { tokenizer, multiplicity }
+            }
+        }
+
+        impl<T> StringMetric for $name<T> where T: Tokenizer
+        {
+            fn distance(&self, str1:&str, str2:&str) -> f64 {
+                1.0 - self.similarity(str1, str2)
+            }
+
+            fn similarity(&self, str1:&str, str2:&str) -> f64 {
+                $sim_fn(str1, str2, &self.tokenizer, self.multiplicity)
+            }
+
+            fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+                self.similarity(str1, str2)
+            }
+        }
+    };
+}
+
+/// Jaccard Simularity as a `StringMetric`, see `jaccard_simularity`.
+#[derive(Clone, Copy, Debug)]
+pub struct Jaccard<T>
+{
+    pub tokenizer:T,
+    pub multiplicity:Multiplicity
+}
+token_set_metric!(Jaccard, jaccard_simularity);
+
+/// Sørensen-Dice Simularity as a `StringMetric`, see `sorensen_dice_simularity`.
+#[derive(Clone, Copy, Debug)]
+pub struct SorensenDice<T>
+{
+    pub tokenizer:T,
+    pub multiplicity:Multiplicity
+}
+token_set_metric!(SorensenDice, sorensen_dice_simularity);
+
+/// Overlap Coefficient as a `StringMetric`, see `overlap_coefficient`.
+#[derive(Clone, Copy, Debug)]
+pub struct Overlap<T>
+{
+    pub tokenizer:T,
+    pub multiplicity:Multiplicity
+}
+token_set_metric!(Overlap, overlap_coefficient);
+
+/// Tversky Index as a `StringMetric`, see `tversky_index`.
+#[derive(Clone, Copy, Debug)]
+pub struct Tversky<T>
+{
+    pub tokenizer:T,
+    pub multiplicity:Multiplicity,
+    pub alpha:f64,
+    pub beta:f64
+}
+
+impl<T> Tversky<T> where T: Tokenizer
+{
+    /// Creates a Tversky metric, `alpha` and `beta` must be at least 0.0.
+    pub fn new(tokenizer:T, multiplicity:Multiplicity, alpha:f64, beta:f64) -> Tversky<T> {
+        assert!(alpha >= 0.0 && beta >= 0.0, "the Tversky weights must be at least 0.0");
+        Tversky { tokenizer, multiplicity, alpha, beta }
+    }
+}
+
+impl<T> StringMetric for Tversky<T> where T: Tokenizer
+{
+    fn distance(&self, str1:&str, str2:&str) -> f64 {
+        1.0 - self.similarity(str1, str2)
+    }
+
+    fn similarity(&self, str1:&str, str2:&str) -> f64 {
+        tversky_index(str1, str2, &self.tokenizer, self.multiplicity, self.alpha, self.beta)
+    }
+
+    fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+        self.similarity(str1, str2)
+    }
+}
+
+/// Cosine Simularity as a `StringMetric`, see `cosine_simularity`.
+#[derive(Clone, Copy, Debug)]
+pub struct Cosine<T>
+{
+    pub tokenizer:T
+}
+
+impl<T> StringMetric for Cosine<T> where T: Tokenizer
+{
+    fn distance(&self, str1:&str, str2:&str) -> f64 {
+        1.0 - self.similarity(str1, str2)
+    }
+
+    fn similarity(&self, str1:&str, str2:&str) -> f64 {
+        cosine_simularity(str1, str2, &self.tokenizer)
+    }
+
+    fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+        self.similarity(str1, str2)
+    }
+}
+
+/// TF-IDF Simularity as a `StringMetric`, see `tfidf_simularity`.
+#[derive(Clone, Debug)]
+pub struct TfIdf<T>
+{
+    pub corpus:Corpus,
+    pub tokenizer:T
+}
+
+impl<T> StringMetric for TfIdf<T> where T: Tokenizer
+{
+    fn distance(&self, str1:&str, str2:&str) -> f64 {
+        1.0 - self.similarity(str1, str2)
+    }
+
+    fn similarity(&self, str1:&str, str2:&str) -> f64 {
+        tfidf_simularity(str1, str2, &self.corpus, &self.tokenizer)
+    }
+
+    fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+        self.similarity(str1, str2)
+    }
+}
+
+/// Soft TF-IDF Simularity as a `StringMetric`, see `soft_tfidf_simularity`.
+#[derive(Clone, Debug)]
+pub struct SoftTfIdf<T>
+{
+    pub corpus:Corpus,
+    pub tokenizer:T,
+    pub threshold:f64
+}
+
+impl<T> StringMetric for SoftTfIdf<T> where T: Tokenizer
+{
+    fn distance(&self, str1:&str, str2:&str) -> f64 {
+        1.0 - self.similarity(str1, str2)
+    }
+
+    fn similarity(&self, str1:&str, str2:&str) -> f64 {
+        soft_tfidf_simularity(str1, str2, &self.corpus, &self.tokenizer, self.threshold)
+    }
+
+    fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+        self.similarity(str1, str2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::token::{QGramTokenizer, UnicodeWordTokenizer, WhitespaceTokenizer};
+
+    fn metrics() -> Vec<Box<dyn StringMetric>> {
+        vec![
+            Box::new(Hamming),
+            Box::new(Levenshtein),
+            Box::new(OptimalStringAlignment),
+            Box::new(DamerauLevenshtein),
+            Box::new(Jaro::default()),
+            Box::new(JaroWinkler::default()),
+            Box::new(Strcmp95 { opts: None, long_strings: true }),
+            Box::new(LongestCommonSubsequence),
+            Box::new(LongestCommonSubstring),
+            Box::new(RatcliffObershelp::default()),
+            Box::new(Jaccard::new(QGramTokenizer::new(2), Multiplicity::Set)),
+            Box::new(SorensenDice::new(QGramTokenizer::new(2).padded(), Multiplicity::Bag)),
+            Box::new(Overlap::new(UnicodeWordTokenizer, Multiplicity::Set)),
+            Box::new(Tversky::new(WhitespaceTokenizer, Multiplicity::Bag, 0.2, 0.8)),
+            Box::new(Cosine { tokenizer: QGramTokenizer::new(3).padded() }),
+            Box::new(TfIdf { corpus: Corpus::from_documents(["paul", "pual", "kitten"].iter(), &QGramTokenizer::new(2)), tokenizer: QGramTokenizer::new(2) }),
+            Box::new(SoftTfIdf { corpus: Corpus::new(), tokenizer: UnicodeWordTokenizer, threshold: 0.9 }),
+        ]
+    }
+
+    #[test]
+    fn empty_strings_are_identical() {
+        for m in metrics() {
+            assert_eq!(m.distance("", ""), 0.0);
+            assert_eq!(m.normalized_distance("", ""), 0.0);
+            assert_eq!(m.normalized_similarity("", ""), 1.0);
+        }
+    }
+
+    #[test]
+    fn normalized_values_are_bounded() {
+        for m in metrics() {
+            let d = m.normalized_distance("Paul Jones", "Jones, Paul");
+            let s = m.normalized_similarity("Paul Jones", "Jones, Paul");
+            assert!((0.0..=1.0).contains(&d));
+            assert!((0.0..=1.0).contains(&s));
+            assert!((d + s - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn paul_pual_edit_distances() {
+        assert_eq!(Levenshtein.distance("paul", "pual"), 2.0);
+        assert_eq!(Levenshtein.similarity("paul", "pual"), 2.0);
+        assert_eq!(Levenshtein.normalized_distance("paul", "pual"), 0.5);
+        assert_eq!(OptimalStringAlignment.distance("paul", "pual"), 1.0);
+        assert_eq!(DamerauLevenshtein.normalized_similarity("paul", "pual"), 0.75);
+    }
+
+    #[test]
+    fn jaro_winkler_matches_free_function() {
+        let expected = jaro_winkler_simularity("hello", "hallo", None);
+        assert_eq!(JaroWinkler::default().similarity("hello", "hallo"), expected);
+        assert_eq!(JaroWinkler::default().distance("hello", "hallo"), 1.0 - expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_tversky_weight() {
+        Tversky::new(WhitespaceTokenizer, Multiplicity::Set, 1.0, -0.5);
+    }
+}
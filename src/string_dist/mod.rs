@@ -15,18 +15,56 @@
 #[macro_use]
 pub mod macros;
 mod helpers;
+mod unit;
+mod normalize;
+
+mod metric;
+mod explain;
+mod keyboard;
+mod ocr;
+
+pub use self::helpers::distance_to_simularity;
+pub use self::unit::Unit;
+pub use self::normalize::{Normalizer, NormalizationForm, Normalized};
+pub use self::explain::{Explain, PrintExplain};
+pub use self::keyboard::{KeyboardLayout, KeyboardCosts};
+pub use self::ocr::{OcrCosts, ocr_distance};
+pub use self::levenshtein::{DistMatrix, CompactDistMatrix};
+pub use self::levenshtein::{EditOp, EditKind};
+pub use self::levenshtein::{CostModel, OperationCosts};
+pub use self::levenshtein::{weighted_levenshtein_distance, weighted_osa_distance, weighted_damerau_levenshtein_distance};
+pub use self::levenshtein::{Confusion, generalized_levenshtein_distance};
+pub use self::levenshtein::{levenshtein_distance_bounded, osa_distance_bounded, damerau_levenshtein_distance_bounded};
+pub use self::metric::{StringMetric, Hamming, Levenshtein, OptimalStringAlignment, DamerauLevenshtein, Jaro, JaroWinkler, Strcmp95};
+pub use self::metric::{LongestCommonSubsequence, LongestCommonSubstring, RatcliffObershelp};
+pub use self::metric::{Jaccard, SorensenDice, Overlap, Tversky, Cosine, TfIdf, SoftTfIdf};
 
 // Edit-Based Simularities
 mod jaro;
 mod hamming;
 mod levenshtein;
 
-pub use self::hamming::hamming_distance;
+pub use self::hamming::{hamming_distance, hamming_distance_by_unit};
+pub use self::jaro::{JWOptions, JWOptionsBuilder, JWOptionsError};
 pub use self::jaro::jaro_simularity;
 pub use self::jaro::jaro_winkler_simularity;
+pub use self::jaro::{jaro_simularity_by_unit, jaro_winkler_simularity_by_unit};
+pub use self::jaro::strcmp95_simularity;
 pub use self::levenshtein::levenshtein::levenshtein_distance;
+pub use self::levenshtein::levenshtein::levenshtein_distance_explained;
+pub use self::levenshtein::levenshtein::levenshtein_simularity;
 pub use self::levenshtein::levenshtein::osa_distance;
+pub use self::levenshtein::levenshtein::osa_distance_explained;
 pub use self::levenshtein::levenshtein::damerau_levenshtein_distance;
+pub use self::levenshtein::levenshtein::damerau_levenshtein_distance_explained;
+pub use self::levenshtein::levenshtein::levenshtein_edit_ops;
+pub use self::levenshtein::levenshtein::osa_edit_ops;
+pub use self::levenshtein::levenshtein::damerau_levenshtein_edit_ops;
+pub use self::levenshtein::levenshtein::{levenshtein_distance_by_unit, osa_distance_by_unit, damerau_levenshtein_distance_by_unit};
+
+// Sequence-Based Simularities
+pub mod subsequence;
+pub use self::lcs::{ratcliff_obershelp_simularity, ratcliff_obershelp_matching_blocks};
 
 // Smith-Waterman
 // Smith-Waterman-Gotoh
@@ -36,11 +74,26 @@ pub use self::levenshtein::levenshtein::damerau_levenshtein_distance;
 
 //    Token-Based Simularities
 
-//Words / n-grams
+// Words / n-grams
+mod token;
 pub mod lcs;
+
+pub use self::token::{Tokenizer, TokenBag, TokenBagIter, Multiplicity, QGramTokenizer, WhitespaceTokenizer, PunctuationTokenizer, UnicodeWordTokenizer, WordNGramTokenizer};
+
 // Jaccard
 // Dice
+mod jaccard;
+
+pub use self::jaccard::{jaccard_simularity, sorensen_dice_simularity, overlap_coefficient, tversky_index};
 // Cosine Simularity
+mod cosine;
+
+pub use self::cosine::{Profile, cosine_simularity};
+
+// TF-IDF
+mod tfidf;
+
+pub use self::tfidf::{Corpus, CorpusError, tfidf_simularity};
 
 
 
@@ -49,6 +102,9 @@ pub mod lcs;
 //
 // Monge-Elkan
 // Soft TF-IDF
+mod soft_tfidf;
+
+pub use self::soft_tfidf::soft_tfidf_simularity;
 
 
 
@@ -0,0 +1,231 @@
+use caseless::default_case_fold_str;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+use super::metric::StringMetric;
+
+/// The Unicode normalization forms, see https://unicode.org/reports/tr15/
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NormalizationForm
+{
+    /// Canonical composition, `e` + combining acute becomes `é`
+    Nfc,
+    /// Canonical decomposition, `é` becomes `e` + combining acute
+    Nfd,
+    /// Compatibility composition, also folds compatibility characters such as `ﬁ` to `fi` and `²` to `2`
+    Nfkc,
+    /// Compatibility decomposition
+    Nfkd
+}
+
+/// # Normalizer
+/// A preprocessing pipeline applied to both strings before they are compared, so that differences which
+/// do not matter to the caller (case, accents, spacing, punctuation) do not count as edits.
+///
+/// The enabled steps always run in this order:
+/// 1. `form` - the Unicode normalization form, so that compatibility characters such as `ℌ` and `Ǆ` are
+///    folded to `H` and `DŽ` before the next steps see them
+/// 2. `case_fold` - full Unicode case folding, `Straße` becomes `strasse`
+/// 3. `strip_diacritics` - removes combining marks after canonical decomposition, `Crème` becomes `Creme`.
+///    Letters with no decomposition, such as `ø` and `ł`, are kept
+/// 4. `form` again, as case folding and stripping diacritics can leave a string that is not in the form
+///    (as NFKC_Casefold does)
+/// 5. `remove_punctuation` - removes every character that is not alphanumeric, whitespace or a combining mark
+/// 6. `collapse_whitespace` - trims, and replaces every run of whitespace with a single space
+///
+/// ## Example
+/// ```
+/// use string_distance::{Normalizer, levenshtein_distance};
+///
+/// let normalizer = Normalizer::new().case_fold().strip_diacritics();
+/// let str1 = normalizer.normalize("Crème Brûlée");
+/// let str2 = normalizer.normalize("creme brulee");
+/// assert_eq!(levenshtein_distance(&str1, &str2), 0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Normalizer
+{
+    pub form:Option<NormalizationForm>,
+    pub case_fold:bool,
+    pub strip_diacritics:bool,
+    pub remove_punctuation:bool,
+    pub collapse_whitespace:bool
+}
+
+impl Normalizer
+{
+    /// Creates a normalizer with every step disabled, `normalize` returns the string unchanged.
+    pub fn new() -> Normalizer {
+        Normalizer::default()
+    }
+
+    /// Enables every step, with NFKC normalization.
+    pub fn all() -> Normalizer {
+        Normalizer::new()
+            .form(NormalizationForm::Nfkc)
+            .case_fold()
+            .strip_diacritics()
+            .remove_punctuation()
+            .collapse_whitespace()
+    }
+
+    pub fn form(mut self, form:NormalizationForm) -> Normalizer {
+        self.form = Some(form);
+        self
+    }
+
+    pub fn case_fold(mut self) -> Normalizer {
+        self.case_fold = true;
+        self
+    }
+
+    pub fn strip_diacritics(mut self) -> Normalizer {
+        self.strip_diacritics = true;
+        self
+    }
+
+    pub fn remove_punctuation(mut self) -> Normalizer {
+        self.remove_punctuation = true;
+        self
+    }
+
+    pub fn collapse_whitespace(mut self) -> Normalizer {
+        self.collapse_whitespace = true;
+        self
+    }
+
+    /// Applies the enabled steps to `s`.
+    pub fn normalize(&self, s:&str) -> String
+    {
+        let mut s = self.apply_form(s.to_string());
+
+        if self.case_fold || self.strip_diacritics {
+            if self.case_fold {
+                s = default_case_fold_str(&s);
+            }
+            if self.strip_diacritics {
+                s = s.nfd().filter(|ch| !is_combining_mark(*ch)).nfc().collect();
+            }
+            s = self.apply_form(s);
+        }
+        if self.remove_punctuation {
+            s = s.chars().filter(|ch| ch.is_alphanumeric() || ch.is_whitespace() || is_combining_mark(*ch)).collect();
+        }
+        if self.collapse_whitespace {
+            s = s.split_whitespace().collect::<Vec<&str>>().join(" ");
+        }
+
+        s
+    }
+
+    fn apply_form(&self, s:String) -> String {
+        match self.form {
+            Some(NormalizationForm::Nfc) => s.nfc().collect(),
+            Some(NormalizationForm::Nfd) => s.nfd().collect(),
+            Some(NormalizationForm::Nfkc) => s.nfkc().collect(),
+            Some(NormalizationForm::Nfkd) => s.nfkd().collect(),
+            None => s
+        }
+    }
+}
+
+/// # Normalized
+/// Configures any `StringMetric` with a `Normalizer`, both strings are normalized before every comparison.
+///
+/// ## Example
+/// ```
+/// use string_distance::{StringMetric, Normalized, Normalizer, JaroWinkler};
+///
+/// let metric = Normalized::new(Normalizer::all(), JaroWinkler::default());
+/// assert_eq!(metric.normalized_similarity("Crème  Brûlée!", "creme brulee"), 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Normalized<M>
+{
+    pub normalizer:Normalizer,
+    pub metric:M
+}
+
+impl<M> Normalized<M> where M: StringMetric
+{
+    pub fn new(normalizer:Normalizer, metric:M) -> Normalized<M> {
+        Normalized { normalizer, metric }
+    }
+}
+
+impl<M> StringMetric for Normalized<M> where M: StringMetric
+{
+    fn distance(&self, str1:&str, str2:&str) -> f64 {
+        self.metric.distance(&self.normalizer.normalize(str1), &self.normalizer.normalize(str2))
+    }
+
+    fn similarity(&self, str1:&str, str2:&str) -> f64 {
+        self.metric.similarity(&self.normalizer.normalize(str1), &self.normalizer.normalize(str2))
+    }
+
+    fn normalized_distance(&self, str1:&str, str2:&str) -> f64 {
+        self.metric.normalized_distance(&self.normalizer.normalize(str1), &self.normalizer.normalize(str2))
+    }
+
+    fn normalized_similarity(&self, str1:&str, str2:&str) -> f64 {
+        self.metric.normalized_similarity(&self.normalizer.normalize(str1), &self.normalizer.normalize(str2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::metric::Levenshtein;
+
+    #[test]
+    fn no_steps_is_identity() {
+        assert_eq!(Normalizer::new().normalize(" Crème  Brûlée! "), " Crème  Brûlée! ");
+    }
+
+    #[test]
+    fn full_case_folding() {
+        let normalizer = Normalizer::new().case_fold();
+        assert_eq!(normalizer.normalize("Straße"), "strasse");
+        assert_eq!(normalizer.normalize("ΣΊΣΥΦΟΣ"), normalizer.normalize("σίσυφος"));
+    }
+
+    #[test]
+    fn diacritics() {
+        let normalizer = Normalizer::new().strip_diacritics();
+        assert_eq!(normalizer.normalize("Crème Brûlée"), "Creme Brulee");
+        assert_eq!(normalizer.normalize("Cre\u{300}me"), "Creme");
+        assert_eq!(normalizer.normalize("Øresund"), "Øresund");
+    }
+
+    #[test]
+    fn normalization_forms() {
+        assert_eq!(Normalizer::new().form(NormalizationForm::Nfc).normalize("e\u{301}"), "\u{e9}");
+        assert_eq!(Normalizer::new().form(NormalizationForm::Nfd).normalize("\u{e9}"), "e\u{301}");
+        assert_eq!(Normalizer::new().form(NormalizationForm::Nfkc).normalize("ﬁle²"), "file2");
+        assert_eq!(Normalizer::new().form(NormalizationForm::Nfkd).normalize("ﬁ\u{e9}"), "fie\u{301}");
+    }
+
+    #[test]
+    fn compatibility_characters() {
+        let all = Normalizer::all();
+        assert_eq!(all.normalize("ℌ"), all.normalize("h"));
+        assert_eq!(all.normalize("ℌ"), "h");
+        assert_eq!(all.normalize("Ǆ"), "dz");
+        assert_eq!(all.normalize("ＣＡＦÉ ﬁle"), "cafe file");
+        assert_eq!(Normalizer::new().form(NormalizationForm::Nfkd).case_fold().normalize("Ǆ"), "dz\u{30c}");
+    }
+
+    #[test]
+    fn punctuation_and_whitespace() {
+        let normalizer = Normalizer::new().remove_punctuation().collapse_whitespace();
+        assert_eq!(normalizer.normalize("  Smith,  John -- Jr. "), "Smith John Jr");
+        assert_eq!(Normalizer::new().remove_punctuation().normalize("e\u{301}!"), "e\u{301}");
+    }
+
+    #[test]
+    fn normalized_metric() {
+        let metric = Normalized::new(Normalizer::all(), Levenshtein);
+        assert_eq!(metric.distance("Crème Brûlée", "creme brulee"), 0.0);
+        assert_eq!(metric.distance("O'Brien", "obrian"), 1.0);
+        assert_eq!(Levenshtein.distance("O'Brien", "obrian"), 4.0);
+    }
+}
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use super::levenshtein::{CostModel, Confusion, OperationCosts, generalized_levenshtein_distance};
+
+/// Characters and character sequences commonly confused by OCR, and the cost of each confusion.
+/// Every confusion applies in both directions.
+static OCR_CONFUSIONS:[(&str, &str, f64); 24] = [
+    ("0", "O", 0.2), ("0", "o", 0.3), ("O", "o", 0.3), ("O", "Q", 0.4), ("O", "D", 0.4),
+    ("1", "l", 0.2), ("1", "I", 0.2), ("l", "I", 0.2), ("1", "i", 0.4), ("l", "i", 0.4),
+    ("5", "S", 0.3), ("8", "B", 0.3), ("2", "Z", 0.4), ("6", "G", 0.4), ("6", "b", 0.5),
+    ("9", "g", 0.5), ("g", "q", 0.5), ("u", "v", 0.4), ("c", "e", 0.5),
+    ("rn", "m", 0.3), ("cl", "d", 0.3), ("vv", "w", 0.3), ("ii", "u", 0.4), ("in", "m", 0.5)
+];
+
+/// # OCR Costs
+/// A cost model for comparing text produced by optical character recognition, where characters that
+/// look alike (`0`/`O`, `1`/`l`/`I`) and multi-character confusions (`rn`/`m`, `cl`/`d`) are penalized
+/// less than genuine differences.
+///
+/// Single character confusions are available through `CostModel`, so `OcrCosts` can drive any weighted
+/// edit distance. Multi-character confusions are only applied by `ocr_distance` (or by passing
+/// `confusions()` to `generalized_levenshtein_distance`).
+///
+/// ## Example
+/// ```
+/// use string_distance::{OcrCosts, ocr_distance};
+///
+/// let ocr = OcrCosts::default();
+/// assert_eq!(ocr_distance("Invoice 1O0", "lnvoice 100", &ocr), 0.4);
+/// assert!(ocr_distance("modern", "modem", &ocr) < 1.0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct OcrCosts
+{
+    pub costs:OperationCosts,
+    single:HashMap<(char, char), f64>,
+    confusions:Vec<Confusion>
+}
+
+impl OcrCosts
+{
+    /// Creates a cost model with no confusions, equivalent to `costs`.
+    pub fn new(costs:OperationCosts) -> OcrCosts {
+        OcrCosts { costs, single: HashMap::new(), confusions: Vec::new() }
+    }
+
+    /// Adds a confusion between `a` and `b`, in both directions.
+    pub fn with_confusion(mut self, a:&str, b:&str, cost:f64) -> OcrCosts {
+        self.add_confusion(a, b, cost);
+        self
+    }
+
+    /// Adds a confusion between `a` and `b`, in both directions.
+    /// Replaces the cost of an existing confusion between `a` and `b`.
+    pub fn add_confusion(&mut self, a:&str, b:&str, cost:f64) {
+        let mut ca = a.chars();
+        let mut cb = b.chars();
+        if let (Some(cha), None, Some(chb), None) = (ca.next(), ca.next(), cb.next(), cb.next()) {
+            self.single.insert((cha, chb), cost);
+            self.single.insert((chb, cha), cost);
+        } else {
+            let (a, b) = (Confusion::new(a, b, cost), Confusion::new(b, a, cost));
+            let same = |c:&Confusion, other:&Confusion| c.from == other.from && c.to == other.to;
+            self.confusions.retain(|c| !same(c, &a) && !same(c, &b));
+            self.confusions.push(a);
+            self.confusions.push(b);
+        }
+    }
+
+    /// The multi-character confusions, in the form accepted by `generalized_levenshtein_distance`.
+    pub fn confusions(&self) -> &[Confusion] {
+        &self.confusions
+    }
+}
+
+impl Default for OcrCosts
+{
+    /// The built-in OCR confusion table, with unit cost for every other edit.
+    fn default() -> OcrCosts {
+        OCR_CONFUSIONS.iter().fold(OcrCosts::new(OperationCosts::default()), |ocr, &(a, b, cost)| {
+            ocr.with_confusion(a, b, cost)
+        })
+    }
+}
+
+impl CostModel for OcrCosts
+{
+    fn insert_cost(&self, ch:char) -> f64 { self.costs.insert_cost(ch) }
+
+    fn delete_cost(&self, ch:char) -> f64 { self.costs.delete_cost(ch) }
+
+    fn substitute_cost(&self, from:char, to:char) -> f64 {
+        match self.single.get(&(from, to)) {
+            Some(cost) => cost.min(self.costs.substitute_cost(from, to)),
+            None => self.costs.substitute_cost(from, to)
+        }
+    }
+
+    fn transpose_cost(&self, first:char, second:char) -> f64 { self.costs.transpose_cost(first, second) }
+}
+
+/// # OCR Distance
+/// Calculates the generalized Levenshtein Distance using both the single and the multi-character
+/// confusions of `ocr`.
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `ocr` - The OCR confusions and edit costs, usually `OcrCosts::default()`
+/// * -> The smallest total cost of the edits that turn `str1` into `str2`
+pub fn ocr_distance(str1:&str, str2:&str, ocr:&OcrCosts) -> f64
+{
+    generalized_levenshtein_distance(str1, str2, ocr, ocr.confusions())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EPSILON:f64 = 0.0001;
+    fn cmp_approx (actual:f64, expected:f64) -> bool { (actual - expected).abs() < EPSILON }
+
+    #[test]
+    fn identical_distance() {
+        assert_eq!(ocr_distance("Invoice", "Invoice", &OcrCosts::default()), 0.0);
+    }
+
+    #[test]
+    fn single_char_confusions() {
+        let ocr = OcrCosts::default();
+        assert_eq!(ocr.substitute_cost('0', 'O'), 0.2);
+        assert_eq!(ocr.substitute_cost('O', '0'), 0.2);
+        assert_eq!(ocr.substitute_cost('x', 'O'), 1.0);
+        assert!(cmp_approx(ocr_distance("10O5", "lO0S", &ocr), 0.2 + 0.2 + 0.2 + 0.3));
+    }
+
+    #[test]
+    fn multi_char_confusions() {
+        let ocr = OcrCosts::default();
+        assert_eq!(ocr_distance("corner", "comer", &ocr), 0.3);
+        assert_eq!(ocr_distance("comer", "corner", &ocr), 0.3);
+        assert_eq!(ocr_distance("clear", "dear", &ocr), 0.3);
+        assert_eq!(ocr_distance("vvord", "word", &ocr), 0.3);
+    }
+
+    #[test]
+    fn genuine_differences() {
+        let ocr = OcrCosts::default();
+        assert_eq!(ocr_distance("invoice", "invoices", &ocr), 1.0);
+        assert_eq!(ocr_distance("paid", "void", &ocr), 2.0);
+    }
+
+    #[test]
+    fn replaced_confusions() {
+        let ocr = OcrCosts::new(OperationCosts::default()).with_confusion("rn", "m", 0.5).with_confusion("m", "rn", 0.1);
+        assert_eq!(ocr.confusions().len(), 2);
+        assert_eq!(ocr_distance("corner", "comer", &ocr), 0.1);
+        let ocr = ocr.with_confusion("0", "O", 0.5).with_confusion("O", "0", 0.1);
+        assert_eq!(ocr.substitute_cost('0', 'O'), 0.1);
+    }
+
+    #[test]
+    fn custom_confusions() {
+        let ocr = OcrCosts::new(OperationCosts::default()).with_confusion("ß", "B", 0.1).with_confusion("ri", "n", 0.2);
+        assert_eq!(ocr_distance("Straße", "StraBe", &ocr), 0.1);
+        assert_eq!(ocr_distance("barn", "bam", &ocr), 2.0);
+        assert_eq!(ocr_distance("rian", "nan", &ocr), 0.2);
+    }
+}
@@ -0,0 +1,145 @@
+use super::token::Tokenizer;
+use super::cosine::Profile;
+use super::tfidf::Corpus;
+use super::jaro::jaro_winkler_simularity;
+
+/// # Soft TF-IDF Simularity
+/// The hybrid simularity of Cohen, Ravikumar and Fienberg, a TF-IDF cosine simularity where tokens need not be
+/// equal to match. Each token of `str1` is paired with the token of `str2` it is most similar to by
+/// `jaro_winkler_simularity`, and counts only when that simularity is at least `threshold`:
+///
+/// `Σ V(w, str1) * V(v, str2) * jaro_winkler(w, v)`
+///
+/// where `V` is a token's TF-IDF weight in `corpus`, divided by the length of the string's TF-IDF vector.
+/// Misspelt words still match, while the tokens common to the corpus count for little. Pairing is done from
+/// `str1` to `str2`, so the simularity is not always symmetric.
+///
+/// Cohen et al. use a threshold of 0.9. Jaro-Winkler scores some one letter misspellings of short words just
+/// below that, such as "Smyth" and "Smith" at 0.89, which a threshold of 0.85 allows.
+///
+/// See - https://www.cs.cmu.edu/~wcohen/postscript/ijcai-ws-2003.pdf
+///
+/// ## Example
+/// ```
+/// use string_distance::{Corpus, soft_tfidf_simularity, tfidf_simularity, UnicodeWordTokenizer};
+///
+/// let names = ["Acme Ltd", "Initech Limited", "Globex Ltd", "Jane Doe Limited", "Hooli Ltd"];
+/// let corpus = Corpus::from_documents(names.iter(), &UnicodeWordTokenizer);
+///
+/// let (str1, str2) = ("Jonathon Smyth Ltd", "Jonathan Smith Limited");
+/// assert!(soft_tfidf_simularity(str1, str2, &corpus, &UnicodeWordTokenizer, 0.85) > 0.75);
+/// assert_eq!(tfidf_simularity(str1, str2, &corpus, &UnicodeWordTokenizer), 0.0);
+/// ```
+///
+/// ## Complexity
+/// - Time:  O(|tokens1| * |tokens2|) Jaro-Winkler comparisons
+/// - Space: O(|tokens1| + |tokens2|)
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `corpus` - The document frequencies of the tokens
+/// * `tokenizer` - Splits the strings into tokens, as it split the strings of the corpus
+/// * `threshold` - The Jaro-Winkler Simularity 2 tokens must reach to match, 0.0 <= threshold <= 1.0.
+///   With 1.0 only equal tokens match, as in `tfidf_simularity`
+/// * -> The Soft TF-IDF Simularity, 0.0 <= simularity <= 1.0. Two strings without tokens are identical
+pub fn soft_tfidf_simularity(str1:&str, str2:&str, corpus:&Corpus, tokenizer:&dyn Tokenizer, threshold:f64) -> f64
+{
+    assert!((0.0..=1.0).contains(&threshold), "the Soft TF-IDF threshold must be between 0.0 and 1.0");
+    soft_cosine_simularity(&corpus.profile(str1, tokenizer), &corpus.profile(str2, tokenizer), threshold)
+}
+
+/// The Soft TF-IDF sum over 2 TF-IDF profiles.
+fn soft_cosine_simularity(p1:&Profile, p2:&Profile, threshold:f64) -> f64
+{
+    if p1.is_empty() && p2.is_empty() {
+        return 1.0;
+    }
+    let norms = p1.norm() * p2.norm();
+    if norms == 0.0 {
+        return 0.0;
+    }
+
+    let mut sum = 0.0;
+    for (token1, weight1) in p1.iter()
+    {
+        // The most similar token, ties going to the heavier token and then the first in order, so that
+        // the result does not depend on the order of the profile
+        let mut best:Option<(f64, f64, &str)> = None;
+        for (token2, weight2) in p2.iter()
+        {
+            let sim = if token1 == token2 { 1.0 } else { jaro_winkler_simularity(token1, token2, None) };
+            let better = match best {
+                None => true,
+                Some((best_sim, best_weight, best_token)) =>
+                    (sim, weight2) > (best_sim, best_weight) || ((sim, weight2) == (best_sim, best_weight) && token2 < best_token)
+            };
+            if better {
+                best = Some((sim, weight2, token2));
+            }
+        }
+        if let Some((sim, weight2, _)) = best {
+            if sim >= threshold {
+                sum += weight1 * weight2 * sim;
+            }
+        }
+    }
+    (sum / norms).min(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::tfidf::tfidf_simularity;
+    use super::super::token::UnicodeWordTokenizer;
+
+    #[test]
+    fn empty_strings() {
+        let c = Corpus::from_documents(["Acme Ltd", "Hooli Ltd"].iter(), &UnicodeWordTokenizer);
+        assert_eq!(soft_tfidf_simularity("", "", &c, &UnicodeWordTokenizer, 0.9), 1.0);
+        assert_eq!(soft_tfidf_simularity("Acme", "", &c, &UnicodeWordTokenizer, 0.9), 0.0);
+        assert_eq!(soft_tfidf_simularity("", "Acme", &c, &UnicodeWordTokenizer, 0.9), 0.0);
+    }
+
+    #[test]
+    fn equal_tokens_are_tfidf() {
+        let c = Corpus::from_documents(["Acme Ltd", "Globex Ltd", "Initech Limited"].iter(), &UnicodeWordTokenizer);
+        for &(str1, str2) in [("Acme Ltd", "Acme Ltd"), ("Acme Ltd", "Globex Ltd"), ("Hooli", "Initech")].iter() {
+            let soft = soft_tfidf_simularity(str1, str2, &c, &UnicodeWordTokenizer, 0.99);
+            assert!((soft - tfidf_simularity(str1, str2, &c, &UnicodeWordTokenizer)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn misspelt_tokens_match() {
+        let names = ["Acme Ltd", "Initech Limited", "Globex Ltd", "Jane Doe Limited", "Hooli Ltd"];
+        let c = Corpus::from_documents(names.iter(), &UnicodeWordTokenizer);
+        let (str1, str2) = ("Jonathon Smyth Ltd", "Jonathan Smith Limited");
+        let soft = soft_tfidf_simularity(str1, str2, &c, &UnicodeWordTokenizer, 0.85);
+        assert!(soft > 0.75);
+        assert!(soft_tfidf_simularity(str1, str2, &c, &UnicodeWordTokenizer, 0.9) < soft);
+        assert!(soft_tfidf_simularity(str1, "Jane Doe Limited", &c, &UnicodeWordTokenizer, 0.85) < 0.1);
+    }
+
+    #[test]
+    fn below_threshold_tokens_do_not_count() {
+        let c = Corpus::new();
+        assert_eq!(soft_tfidf_simularity("Smyth", "Smith", &c, &UnicodeWordTokenizer, 0.9), 0.0);
+        let soft = soft_tfidf_simularity("Smyth", "Smith", &c, &UnicodeWordTokenizer, 0.85);
+        assert!((soft - jaro_winkler_simularity("Smyth", "Smith", None)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn threshold_of_one_matches_equal_tokens() {
+        let c = Corpus::from_documents(["Acme Ltd", "Globex Ltd"].iter(), &UnicodeWordTokenizer);
+        assert_eq!(soft_tfidf_simularity("Acme", "Acme", &c, &UnicodeWordTokenizer, 1.0), 1.0);
+        let soft = soft_tfidf_simularity("Acme Ltd", "Acme Limited", &c, &UnicodeWordTokenizer, 1.0);
+        assert!((soft - tfidf_simularity("Acme Ltd", "Acme Limited", &c, &UnicodeWordTokenizer)).abs() < 1e-12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn invalid_threshold() {
+        soft_tfidf_simularity("a", "b", &Corpus::new(), &UnicodeWordTokenizer, 1.5);
+    }
+}
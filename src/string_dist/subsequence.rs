@@ -0,0 +1,272 @@
+use std::cmp::max;
+use std::collections::{BTreeSet, HashMap};
+use std::mem::swap;
+use super::explain::Explain;
+use super::levenshtein::{DistMatrix, CompactDistMatrix};
+use super::unit::Unit;
+
+// Longest Common Subsequence
+// LCS https://en.wikipedia.org/wiki/Longest_common_subsequence_problem
+//
+// Unlike a substring, a subsequence does not need to be contiguous: "ace" is a subsequence of "abcde".
+
+/// # Longest Common Subsequence Length
+/// The number of chars in the longest subsequence shared by both strings.
+///
+/// ## Example
+/// ```
+/// use string_distance::subsequence;
+///
+/// assert_eq!(subsequence::length("AGGTAB", "GXTXAYB"), 4);
+/// ```
+///
+/// ## Complexity
+/// - Time:  O(|str1.len| * |str2.len|)
+/// - Space: O(min(|str1.len|, |str2.len|))
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * -> The length of the longest common subsequence, in chars
+pub fn length(str1:&str, str2:&str) -> usize
+{
+    length_by_unit(str1, str2, Unit::Chars)
+}
+
+/// # Longest Common Subsequence Length (by Unit)
+/// Identical to `length`, but compares the strings in `unit`s, e.g. grapheme clusters or words.
+///
+/// ## Example
+/// ```
+/// use string_distance::{subsequence, Unit};
+///
+/// assert_eq!(subsequence::length_by_unit("the quick brown fox", "the brown lazy fox", Unit::Words), 3);
+/// ```
+pub fn length_by_unit(str1:&str, str2:&str, unit:Unit) -> usize
+{
+    let (mut s1, mut s2) = unit.symbols(str1, str2);
+    // the length is symmetric, so the matrix only needs to hold the shorter string
+    if s2.len() > s1.len() { swap(&mut s1, &mut s2); }
+
+    // m[(i1, i2)] is the length of the longest common subsequence of s1[..=i1] and s2[..=i2]
+    let mut m:CompactDistMatrix<usize> = CompactDistMatrix::new(-1..s1.len() as i32, -1..s2.len() as i32, 2, 0);
+    for (i1, ch1) in (0i32..).zip(s1.iter())
+    {
+        m[(i1, -1)] = 0;
+        for (i2, ch2) in (0i32..).zip(s2.iter())
+        {
+            m[(i1, i2)] = if ch1 == ch2 { m[(i1-1, i2-1)] + 1 } else { max(m[(i1-1, i2)], m[(i1, i2-1)]) };
+        }
+    }
+
+    if s1.is_empty() || s2.is_empty() { 0 } else { *m.get_last() }
+}
+
+/// # Longest Common Subsequence Length (Explained)
+/// Identical to `length`, but fills the full matrix and reports it to `explain`.
+pub fn length_explained(str1:&str, str2:&str, explain:&mut dyn Explain) -> usize
+{
+    let s1:Vec<char> = str1.chars().collect();
+    let s2:Vec<char> = str2.chars().collect();
+    let matrix = build_matrix(&s1, &s2);
+    explain.matrix("Longest Common Subsequence", &matrix);
+    *matrix.get_last()
+}
+
+/// # Longest Common Subsequence
+/// One of the longest subsequences shared by both strings. When there are several, common chars
+/// later in `str1` are preferred.
+///
+/// ## Example
+/// ```
+/// use string_distance::subsequence;
+///
+/// assert_eq!(subsequence::longest("AGGTAB", "GXTXAYB"), "GTAB");
+/// ```
+///
+/// ## Complexity
+/// - Time:  O(|str1.len| * |str2.len|)
+/// - Space: O(|str1.len| * |str2.len|)
+pub fn longest(str1:&str, str2:&str) -> String
+{
+    let s1:Vec<char> = str1.chars().collect();
+    let s2:Vec<char> = str2.chars().collect();
+    let m = build_matrix(&s1, &s2);
+
+    let mut lcs:Vec<char> = Vec::with_capacity(*m.get_last());
+    let (mut i1, mut i2) = (s1.len() as i32 - 1, s2.len() as i32 - 1);
+    while i1 >= 0 && i2 >= 0
+    {
+        if s1[i1 as usize] == s2[i2 as usize] {
+            lcs.push(s1[i1 as usize]);
+            i1 -= 1;
+            i2 -= 1;
+        } else if m[(i1, i2-1)] >= m[(i1-1, i2)] {
+            i2 -= 1;
+        } else {
+            i1 -= 1;
+        }
+    }
+
+    lcs.iter().rev().collect()
+}
+
+/// # All Longest Common Subsequences
+/// Every distinct longest subsequence shared by both strings, sorted.
+/// The number of solutions can grow exponentially with the length of the strings.
+///
+/// ## Example
+/// ```
+/// use string_distance::subsequence;
+///
+/// assert_eq!(subsequence::all_longest("ABCBDAB", "BDCABA"), vec!["BCAB", "BCBA", "BDAB"]);
+/// assert_eq!(subsequence::all_longest("abc", "xyz"), vec![""]);
+/// ```
+pub fn all_longest(str1:&str, str2:&str) -> Vec<String>
+{
+    let s1:Vec<char> = str1.chars().collect();
+    let s2:Vec<char> = str2.chars().collect();
+    let m = build_matrix(&s1, &s2);
+
+    let mut memo:HashMap<(i32, i32), BTreeSet<String>> = HashMap::new();
+    let all = backtrack_all(&m, &s1, &s2, s1.len() as i32 - 1, s2.len() as i32 - 1, &mut memo);
+    all.into_iter().collect()
+}
+
+/// # LCS (Indel) Distance
+/// The number of insertions and deletions needed to turn `str1` into `str2`, i.e. the chars of both
+/// strings that are not part of their longest common subsequence.
+///
+/// ## Example
+/// ```
+/// use string_distance::subsequence;
+///
+/// assert_eq!(subsequence::distance("paul", "pual"), 2);
+/// assert_eq!(subsequence::distance("kitten", "sitting"), 5);
+/// ```
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * -> The Indel Distance, 0 <= distance <= |str1.len| + |str2.len|
+pub fn distance(str1:&str, str2:&str) -> usize
+{
+    str1.chars().count() + str2.chars().count() - 2 * length(str1, str2)
+}
+
+/// Identical to `distance`, but reports the matrix of the longest common subsequence to `explain`.
+pub fn distance_explained(str1:&str, str2:&str, explain:&mut dyn Explain) -> usize
+{
+    let len = str1.chars().count() + str2.chars().count();
+    len - 2 * length_explained(str1, str2, explain)
+}
+
+/// # LCS (Indel) Simularity
+/// The Indel Distance normalized to 0.0 <= simularity <= 1.0 by the combined length of both strings,
+/// which equals `2 * length / (|str1.len| + |str2.len|)`.
+///
+/// ## Example
+/// ```
+/// use string_distance::subsequence;
+///
+/// assert_eq!(subsequence::normalized_simularity("paul", "pual"), 0.75);
+/// assert_eq!(subsequence::normalized_simularity("", ""), 1.0);
+/// ```
+pub fn normalized_simularity(str1:&str, str2:&str) -> f64
+{
+    let len = str1.chars().count() + str2.chars().count();
+    if len == 0 {
+        return 1.0;
+    }
+    2.0 * length(str1, str2) as f64 / len as f64
+}
+
+/// Fills the full matrix, m[(i1, i2)] is the length of the longest common subsequence of s1[..=i1] and s2[..=i2].
+fn build_matrix(s1:&[char], s2:&[char]) -> DistMatrix<usize>
+{
+    let mut m:DistMatrix<usize> = DistMatrix::new(-1..s1.len() as i32, -1..s2.len() as i32, 0);
+    for (i1, ch1) in (0i32..).zip(s1.iter())
+    {
+        for (i2, ch2) in (0i32..).zip(s2.iter())
+        {
+            m[(i1, i2)] = if ch1 == ch2 { m[(i1-1, i2-1)] + 1 } else { max(m[(i1-1, i2)], m[(i1, i2-1)]) };
+        }
+    }
+    m
+}
+
+/// Every longest common subsequence of s1[..=i1] and s2[..=i2], memoized by position.
+fn backtrack_all(m:&DistMatrix<usize>, s1:&[char], s2:&[char], i1:i32, i2:i32,
+                 memo:&mut HashMap<(i32, i32), BTreeSet<String>>) -> BTreeSet<String>
+{
+    if i1 < 0 || i2 < 0 {
+        return vec![String::new()].into_iter().collect();
+    }
+    if let Some(all) = memo.get(&(i1, i2)) {
+        return all.clone();
+    }
+
+    let mut all = BTreeSet::new();
+    if s1[i1 as usize] == s2[i2 as usize] {
+        for mut lcs in backtrack_all(m, s1, s2, i1-1, i2-1, memo) {
+            lcs.push(s1[i1 as usize]);
+            all.insert(lcs);
+        }
+    } else {
+        if m[(i1-1, i2)] == m[(i1, i2)] {
+            all.extend(backtrack_all(m, s1, s2, i1-1, i2, memo));
+        }
+        if m[(i1, i2-1)] == m[(i1, i2)] {
+            all.extend(backtrack_all(m, s1, s2, i1, i2-1, memo));
+        }
+    }
+
+    memo.insert((i1, i2), all.clone());
+    all
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_subsequence() {
+        assert_eq!(length("", ""), 0);
+        assert_eq!(length("abc", ""), 0);
+        assert_eq!(longest("", "abc"), "");
+        assert_eq!(distance("abc", ""), 3);
+        assert_eq!(normalized_simularity("abc", ""), 0.0);
+    }
+
+    #[test]
+    fn subsequence_is_not_contiguous() {
+        assert_eq!(length("abcde", "ace"), 3);
+        assert_eq!(longest("abcde", "ace"), "ace");
+        assert_eq!(distance("abcde", "ace"), 2);
+    }
+
+    #[test]
+    fn longest_is_one_of_all() {
+        let all = all_longest("ABCBDAB", "BDCABA");
+        assert!(all.contains(&longest("ABCBDAB", "BDCABA")));
+        assert!(all.iter().all(|lcs| lcs.chars().count() == length("ABCBDAB", "BDCABA")));
+    }
+
+    #[test]
+    fn non_ascii_subsequence() {
+        assert_eq!(longest("crème brûlée", "creme brulee"), "crme brle");
+        assert_eq!(length_by_unit("cre\u{300}me", "cre\u{300}pe", Unit::Graphemes), 4);
+        assert_eq!(length_by_unit("cre\u{300}me", "cre\u{301}me", Unit::Graphemes), 4);
+    }
+
+    #[test]
+    fn explained_matrix() {
+        struct Last(usize);
+        impl Explain for Last {
+            fn matrix(&mut self, _algorithm:&str, matrix:&DistMatrix<usize>) { self.0 = *matrix.get_last(); }
+        }
+        let mut last = Last(0);
+        assert_eq!(distance_explained("AGGTAB", "GXTXAYB", &mut last), 5);
+        assert_eq!(last.0, 4);
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use super::token::Tokenizer;
+use super::cosine::Profile;
+
+/// The first word of a saved corpus.
+static HEADER:&str = "corpus";
+
+/// # Corpus
+/// The document frequencies of the tokens in a collection of strings: how many of the strings contain each
+/// token. Tokens found in many strings, such as "Inc" or "Corp" among company names, say little about which
+/// string is which, and are given a low inverse document frequency (IDF) weight.
+///
+/// A corpus can be saved as text and loaded again, so the frequencies are learned once.
+///
+/// ## Example
+/// ```
+/// use string_distance::{Corpus, UnicodeWordTokenizer};
+///
+/// let names = ["ABC Corp", "Acme Corp", "Initech Corp", "Initrode Inc"];
+/// let corpus = Corpus::from_documents(names.iter(), &UnicodeWordTokenizer);
+/// assert_eq!(corpus.documents(), 4);
+/// assert_eq!(corpus.document_frequency("Corp"), 3);
+/// assert!(corpus.idf("Corp") < corpus.idf("Acme"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Corpus
+{
+    document_frequencies:HashMap<String, usize>,
+    documents:usize
+}
+
+impl Corpus
+{
+    /// An empty corpus, which weighs every token the same.
+    pub fn new() -> Corpus {
+        Corpus::default()
+    }
+
+    /// Learns the document frequencies of the tokens in `documents`.
+    pub fn from_documents<I, S>(documents:I, tokenizer:&dyn Tokenizer) -> Corpus where I: IntoIterator<Item=S>, S: AsRef<str> {
+        let mut corpus = Corpus::new();
+        for document in documents {
+            corpus.add(document.as_ref(), tokenizer);
+        }
+        corpus
+    }
+
+    /// Adds a string, counting each of its distinct tokens once.
+    pub fn add(&mut self, document:&str, tokenizer:&dyn Tokenizer) {
+        for (token, _) in tokenizer.bag(document).iter() {
+            *self.document_frequencies.entry(token.to_string()).or_insert(0) += 1;
+        }
+        self.documents += 1;
+    }
+
+    /// The number of strings added.
+    pub fn documents(&self) -> usize {
+        self.documents
+    }
+
+    /// The number of strings containing `token`.
+    pub fn document_frequency(&self, token:&str) -> usize {
+        self.document_frequencies.get(token).cloned().unwrap_or(0)
+    }
+
+    /// The smoothed inverse document frequency of `token`, `ln((1 + N) / (1 + df)) + 1` for N strings of which
+    /// df contain the token. A token in every string weighs 1.0, and a token in none weighs the most.
+    pub fn idf(&self, token:&str) -> f64 {
+        ((1 + self.documents) as f64 / (1 + self.document_frequency(token)) as f64).ln() + 1.0
+    }
+
+    /// The TF-IDF profile of `s`, where each token weighs its term frequency times its IDF.
+    /// Like any `Profile`, it can be built once and compared to many others.
+    pub fn profile(&self, s:&str, tokenizer:&dyn Tokenizer) -> Profile {
+        tokenizer.bag(s).iter().map(|(token, count)| (token.to_string(), count as f64 * self.idf(token))).collect()
+    }
+
+    /// Saves the corpus as text. The first line is `corpus`, a tab and the number of strings, followed by a line
+    /// for each token with its document frequency, a tab and the token. Tokens are sorted, and any backslash, tab,
+    /// carriage return or newline in a token is escaped with a backslash.
+    pub fn save<W>(&self, mut writer:W) -> io::Result<()> where W: Write {
+        writeln!(writer, "{}\t{}", HEADER, self.documents)?;
+        let mut tokens:Vec<(&String, &usize)> = self.document_frequencies.iter().collect();
+        tokens.sort();
+        for (token, df) in tokens {
+            writeln!(writer, "{}\t{}", df, escape(token))?;
+        }
+        Ok(())
+    }
+
+    /// Loads a corpus saved by `save`.
+    pub fn load<R>(reader:R) -> Result<Corpus, CorpusError> where R: BufRead {
+        let mut lines = reader.lines();
+        let header = lines.next().unwrap_or_else(|| Ok(String::new()))?;
+        let documents = match header.split_once('\t') {
+            Some((header, documents)) if header == HEADER => documents.parse().map_err(|_| CorpusError::Header)?,
+            _ => return Err(CorpusError::Header)
+        };
+
+        let mut corpus = Corpus { document_frequencies: HashMap::new(), documents };
+        for (i, line) in lines.enumerate()
+        {
+            let line = line?;
+            let parsed = line.split_once('\t').and_then(|(df, token)| Some((df.parse::<usize>().ok()?, unescape(token)?)));
+            match parsed {
+                Some((df, token)) if df <= documents && !corpus.document_frequencies.contains_key(&token) => {
+                    corpus.document_frequencies.insert(token, df);
+                },
+                _ => return Err(CorpusError::Line(i + 2))
+            }
+        }
+        Ok(corpus)
+    }
+}
+
+/// The reason `Corpus::load` failed.
+#[derive(Debug)]
+pub enum CorpusError
+{
+    /// The reader failed.
+    Io(io::Error),
+    /// The first line is not `corpus`, a tab and the number of strings.
+    Header,
+    /// The line, counting from 1, is not a document frequency, a tab and a token not seen before,
+    /// or the frequency is more than the number of strings.
+    Line(usize)
+}
+
+impl fmt::Display for CorpusError
+{
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CorpusError::Io(ref e) => write!(f, "failed to read corpus: {}", e),
+            CorpusError::Header => write!(f, "corpus header must be \"{}\", a tab and the number of documents", HEADER),
+            CorpusError::Line(line) => write!(f, "invalid document frequency on line {}", line)
+        }
+    }
+}
+
+impl Error for CorpusError
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            CorpusError::Io(ref e) => Some(e),
+            _ => None
+        }
+    }
+}
+
+impl From<io::Error> for CorpusError
+{
+    fn from(e:io::Error) -> CorpusError {
+        CorpusError::Io(e)
+    }
+}
+
+fn escape(token:&str) -> String
+{
+    let mut escaped = String::with_capacity(token.len());
+    for ch in token.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(ch)
+        }
+    }
+    escaped
+}
+
+/// The token escaped by `escape`, None if it holds an unknown escape or a raw tab.
+fn unescape(escaped:&str) -> Option<String>
+{
+    let mut token = String::with_capacity(escaped.len());
+    let mut chars = escaped.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => token.push(match chars.next()? {
+                '\\' => '\\',
+                't' => '\t',
+                'r' => '\r',
+                'n' => '\n',
+                _ => return None
+            }),
+            '\t' => return None,
+            _ => token.push(ch)
+        }
+    }
+    Some(token)
+}
+
+/// # TF-IDF Simularity
+/// The cosine simularity of the strings' TF-IDF profiles, where each token weighs its term frequency times its
+/// inverse document frequency in `corpus`. Tokens common to many strings of the corpus count for less than the
+/// rare tokens that tell strings apart.
+///
+/// To compare one string to many, build a profile of each once with `Corpus::profile` and use
+/// `Profile::cosine_simularity`.
+///
+/// See - https://en.wikipedia.org/wiki/Tf%E2%80%93idf
+///
+/// ## Example
+/// ```
+/// use string_distance::{Corpus, tfidf_simularity, cosine_simularity, UnicodeWordTokenizer};
+///
+/// let names = ["ABC Corp", "Acme Corp", "Initech Corp", "Globex Corp", "Initrode Inc"];
+/// let corpus = Corpus::from_documents(names.iter(), &UnicodeWordTokenizer);
+///
+/// let tfidf = tfidf_simularity("ABC Corp", "XYZ Corp", &corpus, &UnicodeWordTokenizer);
+/// assert!(tfidf < cosine_simularity("ABC Corp", "XYZ Corp", &UnicodeWordTokenizer));
+/// assert!(tfidf < tfidf_simularity("ABC Corp", "ABC Inc", &corpus, &UnicodeWordTokenizer));
+/// ```
+///
+/// ## Parameters
+/// * `str1` - The first string to compare
+/// * `str2` - The Second string to compare
+/// * `corpus` - The document frequencies of the tokens
+/// * `tokenizer` - Splits the strings into tokens, as it split the strings of the corpus
+/// * -> The TF-IDF Simularity, 0.0 <= simularity <= 1.0. Two strings without tokens are identical
+pub fn tfidf_simularity(str1:&str, str2:&str, corpus:&Corpus, tokenizer:&dyn Tokenizer) -> f64
+{
+    corpus.profile(str1, tokenizer).cosine_simularity(&corpus.profile(str2, tokenizer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::cosine::cosine_simularity;
+    use super::super::token::{QGramTokenizer, UnicodeWordTokenizer};
+
+    static COMPANIES:[&str; 6] = [
+        "ABC Corporation", "ABC Corp", "D & H Enterprises, Inc.", "Acme Widgets Inc", "Initech Corp", "Globex Inc"
+    ];
+
+    fn corpus() -> Corpus {
+        Corpus::from_documents(COMPANIES.iter(), &UnicodeWordTokenizer)
+    }
+
+    #[test]
+    fn document_frequencies() {
+        let c = Corpus::from_documents(vec!["a a b".to_string(), "a c".to_string()], &UnicodeWordTokenizer);
+        assert_eq!(c.documents(), 2);
+        assert_eq!((c.document_frequency("a"), c.document_frequency("b"), c.document_frequency("z")), (2, 1, 0));
+        assert_eq!(c.idf("a"), 1.0);
+        assert_eq!(c.idf("z"), 3f64.ln() + 1.0);
+    }
+
+    #[test]
+    fn empty_corpus_is_cosine() {
+        let bigrams = QGramTokenizer::new(2);
+        for &(str1, str2) in [("Jonathan", "Jonathon"), ("", ""), ("abc", "")].iter() {
+            let tfidf = tfidf_simularity(str1, str2, &Corpus::new(), &bigrams);
+            assert!((tfidf - cosine_simularity(str1, str2, &bigrams)).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn common_tokens_count_less() {
+        let (c, words) = (corpus(), &UnicodeWordTokenizer);
+        assert!(c.idf("Inc") < c.idf("Globex"));
+        assert!(tfidf_simularity("Initech Inc", "Globex Inc", &c, words) < cosine_simularity("Initech Inc", "Globex Inc", words));
+        assert!(tfidf_simularity("Initech Corp", "Initech Inc", &c, words) > cosine_simularity("Initech Corp", "Initech Inc", words));
+        assert_eq!(tfidf_simularity("D & H Enterprises Inc", "D & H Enterprises, Inc.", &c, words), 1.0);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let mut c = corpus();
+        c.add("tab\there \\ new\nline", &QGramTokenizer::new(3));
+        let mut saved:Vec<u8> = Vec::new();
+        c.save(&mut saved).unwrap();
+        assert!(String::from_utf8(saved.clone()).unwrap().starts_with("corpus\t7\n1\t\\the\n1\t\\nli\n"));
+        assert_eq!(Corpus::load(&saved[..]).unwrap(), c);
+    }
+
+    #[test]
+    fn load_errors() {
+        let load = |text:&str| Corpus::load(text.as_bytes());
+        assert!(load("corpus\t0\n").unwrap().documents() == 0);
+        assert!(matches!(load(""), Err(CorpusError::Header)));
+        assert!(matches!(load("corpus 2\n"), Err(CorpusError::Header)));
+        assert!(matches!(load("corpus\t2\n1\tInc\nInc\t1\n"), Err(CorpusError::Line(3))));
+        assert!(matches!(load("corpus\t2\n3\tInc\n"), Err(CorpusError::Line(2))));
+        assert!(matches!(load("corpus\t2\n1\tInc\n1\tInc\n"), Err(CorpusError::Line(3))));
+        assert!(matches!(load("corpus\t2\n1\ta\\xb\n"), Err(CorpusError::Line(2))));
+        assert_eq!(CorpusError::Line(3).to_string(), "invalid document frequency on line 3");
+    }
+}
@@ -0,0 +1,367 @@
+use std::iter::{repeat_n, FromIterator};
+use std::collections::hash_map;
+use std::collections::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Whether token-based metrics count the distinct tokens of a string (`Set`), or every occurrence (`Bag`).
+/// With `Bag`, `a a b` and `a b` share 2 tokens out of 3, with `Set` they are identical.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Multiplicity
+{
+    #[default]
+    Set,
+    Bag
+}
+
+/// # Token Bag
+/// A multiset of tokens, the count of every distinct token of a string. Token-based metrics compare
+/// bags either as sets (each distinct token counts once) or as multisets (each token counts as often
+/// as it occurs).
+///
+/// ## Example
+/// ```
+/// use string_distance::{Tokenizer, WhitespaceTokenizer};
+///
+/// let bag = WhitespaceTokenizer.bag("to be or not to be");
+/// assert_eq!(bag.count("be"), 2);
+/// assert_eq!(bag.len(), 6);
+/// assert_eq!(bag.distinct(), 4);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TokenBag
+{
+    counts:HashMap<String, usize>,
+    len:usize
+}
+
+impl TokenBag
+{
+    pub fn new() -> TokenBag {
+        TokenBag::default()
+    }
+
+    /// Adds one occurrence of `token`.
+    pub fn insert(&mut self, token:String) {
+        *self.counts.entry(token).or_insert(0) += 1;
+        self.len += 1;
+    }
+
+    /// The number of occurrences of `token`, 0 if it is not in the bag.
+    pub fn count(&self, token:&str) -> usize {
+        self.counts.get(token).cloned().unwrap_or(0)
+    }
+
+    /// The number of tokens, counting every occurrence.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of distinct tokens.
+    pub fn distinct(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// The number of tokens counted as `multiplicity`, the distinct tokens or every occurrence.
+    pub fn size(&self, multiplicity:Multiplicity) -> usize {
+        match multiplicity {
+            Multiplicity::Set => self.distinct(),
+            Multiplicity::Bag => self.len()
+        }
+    }
+
+    /// The number of tokens shared with `other`. As a `Bag`, a token shared n times is the least of its counts in both.
+    pub fn intersection_size(&self, other:&TokenBag, multiplicity:Multiplicity) -> usize {
+        let (smaller, larger) = if self.distinct() <= other.distinct() { (self, other) } else { (other, self) };
+        smaller.iter().map(|(token, count)| {
+            match (multiplicity, larger.count(token)) {
+                (_, 0) => 0,
+                (Multiplicity::Set, _) => 1,
+                (Multiplicity::Bag, other_count) => count.min(other_count)
+            }
+        }).sum()
+    }
+
+    /// The number of tokens in either bag. As a `Bag`, a token is counted the most times it occurs in either.
+    pub fn union_size(&self, other:&TokenBag, multiplicity:Multiplicity) -> usize {
+        self.size(multiplicity) + other.size(multiplicity) - self.intersection_size(other, multiplicity)
+    }
+
+    /// Every distinct token and its count, in no particular order.
+    pub fn iter(&self) -> TokenBagIter<'_> {
+        TokenBagIter { inner: self.counts.iter() }
+    }
+}
+
+impl FromIterator<String> for TokenBag
+{
+    fn from_iter<I: IntoIterator<Item=String>>(tokens:I) -> TokenBag {
+        let mut bag = TokenBag::new();
+        for token in tokens { bag.insert(token); }
+        bag
+    }
+}
+
+/// Iterates the distinct tokens of a `TokenBag` and their counts.
+pub struct TokenBagIter<'a>
+{
+    inner:hash_map::Iter<'a, String, usize>
+}
+
+impl<'a> Iterator for TokenBagIter<'a>
+{
+    type Item = (&'a str, usize);
+
+    fn next(&mut self) -> Option<(&'a str, usize)> {
+        self.inner.next().map(|(token, &count)| (token.as_str(), count))
+    }
+}
+
+/// # Tokenizer
+/// Splits a string into tokens, for token-based metrics that compare strings by the tokens they share
+/// rather than by edits. Tokens are returned in the order they occur, `bag` counts them.
+///
+/// Tokenizers do not change case or accents, apply a `Normalizer` first to ignore them.
+///
+/// ## Example
+/// ```
+/// use string_distance::{Tokenizer, QGramTokenizer, UnicodeWordTokenizer};
+///
+/// assert_eq!(QGramTokenizer::new(2).tokenize("paul"), vec!["pa", "au", "ul"]);
+/// assert_eq!(UnicodeWordTokenizer.tokenize("Smith, John-Paul"), vec!["Smith", "John", "Paul"]);
+/// ```
+pub trait Tokenizer
+{
+    /// The tokens of `s`, in order.
+    fn tokenize(&self, s:&str) -> Vec<String>;
+
+    /// The multiset of the tokens of `s`.
+    fn bag(&self, s:&str) -> TokenBag {
+        self.tokenize(s).into_iter().collect()
+    }
+}
+
+/// # Q-Gram Tokenizer
+/// Splits a string into its overlapping substrings of `q` chars, e.g. the bigrams of `paul` are `pa`, `au`, `ul`.
+/// A string shorter than `q` chars has no q-grams.
+///
+/// With padding, `q - 1` start and end chars are added around the string first, so the first and last chars
+/// appear in as many q-grams as the others and a prefix or suffix match counts for more, e.g. the padded
+/// bigrams of `paul` are `#p`, `pa`, `au`, `ul`, `l$`.
+///
+/// ## Example
+/// ```
+/// use string_distance::{Tokenizer, QGramTokenizer};
+///
+/// assert_eq!(QGramTokenizer::new(3).tokenize("paul"), vec!["pau", "aul"]);
+/// assert_eq!(QGramTokenizer::new(2).padded().tokenize("paul"), vec!["#p", "pa", "au", "ul", "l$"]);
+/// assert_eq!(QGramTokenizer::new(2).pad('^', '^').tokenize("ab"), vec!["^a", "ab", "b^"]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct QGramTokenizer
+{
+    q:usize,
+    padding:Option<(char, char)>
+}
+
+impl QGramTokenizer
+{
+    /// Creates an unpadded q-gram tokenizer, `q` must be at least 1.
+    pub fn new(q:usize) -> QGramTokenizer {
+        assert!(q > 0, "q-grams must have at least 1 char");
+        QGramTokenizer { q, padding: None }
+    }
+
+    /// Pads the string with `#` at the start and `$` at the end.
+    pub fn padded(self) -> QGramTokenizer {
+        self.pad('#', '$')
+    }
+
+    /// Pads the string with `start` at the start and `end` at the end.
+    pub fn pad(mut self, start:char, end:char) -> QGramTokenizer {
+        self.padding = Some((start, end));
+        self
+    }
+
+    /// The number of chars in a q-gram.
+    pub fn q(&self) -> usize {
+        self.q
+    }
+
+    /// The start and end padding chars, if the string is padded.
+    pub fn padding(&self) -> Option<(char, char)> {
+        self.padding
+    }
+}
+
+impl Tokenizer for QGramTokenizer
+{
+    fn tokenize(&self, s:&str) -> Vec<String>
+    {
+        let chars:Vec<char> = match self.padding {
+            Some((start, end)) => repeat_n(start, self.q - 1)
+                .chain(s.chars())
+                .chain(repeat_n(end, self.q - 1))
+                .collect(),
+            None => s.chars().collect()
+        };
+        chars.windows(self.q).map(|gram| gram.iter().collect()).collect()
+    }
+}
+
+/// # Whitespace Tokenizer
+/// Splits a string into the words between whitespace, punctuation stays part of the words.
+///
+/// ## Example
+/// ```
+/// use string_distance::{Tokenizer, WhitespaceTokenizer};
+///
+/// assert_eq!(WhitespaceTokenizer.tokenize(" D&H  Enterprises, Inc. "), vec!["D&H", "Enterprises,", "Inc."]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WhitespaceTokenizer;
+
+impl Tokenizer for WhitespaceTokenizer
+{
+    fn tokenize(&self, s:&str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+}
+
+/// # Punctuation Tokenizer
+/// Splits a string at every char that is not alphanumeric, i.e. at whitespace and punctuation.
+///
+/// ## Example
+/// ```
+/// use string_distance::{Tokenizer, PunctuationTokenizer};
+///
+/// assert_eq!(PunctuationTokenizer.tokenize(" D&H  Enterprises, Inc. "), vec!["D", "H", "Enterprises", "Inc"]);
+/// assert_eq!(PunctuationTokenizer.tokenize("O'Brien"), vec!["O", "Brien"]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PunctuationTokenizer;
+
+impl Tokenizer for PunctuationTokenizer
+{
+    fn tokenize(&self, s:&str) -> Vec<String> {
+        s.split(|ch:char| !ch.is_alphanumeric()).filter(|token| !token.is_empty()).map(String::from).collect()
+    }
+}
+
+/// # Unicode Word Tokenizer
+/// Splits a string into words by the Unicode word boundary rules (UAX #29), as `Unit::Words` does.
+/// Unlike `PunctuationTokenizer`, apostrophes and periods within a word, such as `O'Brien` or `3.5`,
+/// and combining marks stay part of the word.
+///
+/// See - https://unicode.org/reports/tr29/#Word_Boundaries
+///
+/// ## Example
+/// ```
+/// use string_distance::{Tokenizer, UnicodeWordTokenizer};
+///
+/// assert_eq!(UnicodeWordTokenizer.tokenize("O'Brien paid $3.50."), vec!["O'Brien", "paid", "3.50"]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnicodeWordTokenizer;
+
+impl Tokenizer for UnicodeWordTokenizer
+{
+    fn tokenize(&self, s:&str) -> Vec<String> {
+        s.unicode_words().map(String::from).collect()
+    }
+}
+
+/// # Word N-Gram Tokenizer
+/// Splits a string into its overlapping runs of `n` Unicode words, joined by a single space.
+/// A string with fewer than `n` words has no n-grams.
+///
+/// ## Example
+/// ```
+/// use string_distance::{Tokenizer, WordNGramTokenizer};
+///
+/// assert_eq!(WordNGramTokenizer::new(2).tokenize("the quick, brown fox"), vec!["the quick", "quick brown", "brown fox"]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WordNGramTokenizer
+{
+    n:usize
+}
+
+impl WordNGramTokenizer
+{
+    /// Creates a word n-gram tokenizer, `n` must be at least 1.
+    pub fn new(n:usize) -> WordNGramTokenizer {
+        assert!(n > 0, "word n-grams must have at least 1 word");
+        WordNGramTokenizer { n }
+    }
+
+    /// The number of words in an n-gram.
+    pub fn n(&self) -> usize {
+        self.n
+    }
+}
+
+impl Tokenizer for WordNGramTokenizer
+{
+    fn tokenize(&self, s:&str) -> Vec<String> {
+        let words:Vec<&str> = s.unicode_words().collect();
+        words.windows(self.n).map(|gram| gram.join(" ")).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_tokens() {
+        assert!(QGramTokenizer::new(2).tokenize("").is_empty());
+        assert!(QGramTokenizer::new(3).tokenize("ab").is_empty());
+        assert!(WhitespaceTokenizer.tokenize("   ").is_empty());
+        assert!(PunctuationTokenizer.tokenize("...").is_empty());
+        assert!(UnicodeWordTokenizer.bag("").is_empty());
+        assert!(WordNGramTokenizer::new(3).tokenize("two words").is_empty());
+    }
+
+    #[test]
+    fn padded_qgrams() {
+        assert_eq!(QGramTokenizer::new(3).padded().tokenize("a"), vec!["##a", "#a$", "a$$"]);
+        assert_eq!(QGramTokenizer::new(1).padded().tokenize("ab"), vec!["a", "b"]);
+        assert_eq!(QGramTokenizer::new(2).padded().tokenize(""), vec!["#$"]);
+        assert_eq!(QGramTokenizer::new(2).padded().padding(), Some(('#', '$')));
+    }
+
+    #[test]
+    fn non_ascii_qgrams() {
+        assert_eq!(QGramTokenizer::new(2).tokenize("née"), vec!["né", "ée"]);
+        assert_eq!(PunctuationTokenizer.tokenize("Crème-brûlée"), vec!["Crème", "brûlée"]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_qgrams() {
+        QGramTokenizer::new(0);
+    }
+
+    #[test]
+    fn set_and_bag_sizes() {
+        let (a, b) = (WhitespaceTokenizer.bag("a a a b c"), WhitespaceTokenizer.bag("a a b d"));
+        assert_eq!((a.size(Multiplicity::Set), a.size(Multiplicity::Bag)), (3, 5));
+        assert_eq!(a.intersection_size(&b, Multiplicity::Set), 2);
+        assert_eq!(a.intersection_size(&b, Multiplicity::Bag), 3);
+        assert_eq!(a.union_size(&b, Multiplicity::Set), 4);
+        assert_eq!(a.union_size(&b, Multiplicity::Bag), 6);
+        assert_eq!(b.intersection_size(&a, Multiplicity::Bag), 3);
+    }
+
+    #[test]
+    fn bag_counts() {
+        let bag = QGramTokenizer::new(2).bag("banana");
+        assert_eq!((bag.count("an"), bag.count("na"), bag.count("ba"), bag.count("xx")), (2, 2, 1, 0));
+        assert_eq!((bag.len(), bag.distinct()), (5, 3));
+        assert_eq!(bag.iter().map(|(_, count)| count).sum::<usize>(), bag.len());
+    }
+}
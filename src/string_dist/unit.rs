@@ -0,0 +1,103 @@
+use std::char;
+use std::collections::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// # Unit
+/// The unit that strings are compared in, i.e. what a single edit, match or transposition applies to.
+///
+/// * `Bytes` - UTF-8 bytes, `é` is 2 units
+/// * `Chars` - Unicode scalar values, the default. `é` written as `e` + a combining accent is 2 units
+/// * `Graphemes` - Extended grapheme clusters, what a reader perceives as a single character.
+///   `é` is 1 unit however it is written, and so is an emoji ZWJ sequence such as 👨‍👩‍👧
+/// * `Words` - Unicode words, punctuation and whitespace between the words are ignored
+///
+/// ## Example
+/// ```
+/// use string_distance::{Unit, levenshtein_distance, levenshtein_distance_by_unit};
+///
+/// let composed = "caf\u{e9}";
+/// let decomposed = "cafe\u{301}";
+/// assert_eq!(levenshtein_distance(composed, decomposed), 2);
+/// assert_eq!(levenshtein_distance_by_unit("cafe\u{301}s", "cafe\u{301}", Unit::Graphemes), 1);
+/// assert_eq!(levenshtein_distance_by_unit("the quick brown fox", "the quick red fox", Unit::Words), 1);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Unit
+{
+    Bytes,
+    #[default]
+    Chars,
+    Graphemes,
+    Words
+}
+
+impl Unit
+{
+    /// Splits both strings into units, and maps every distinct unit to its own symbol so that the
+    /// character based algorithms can compare any unit. Equal units map to equal symbols in both strings.
+    ///
+    /// `Chars` are their own symbols, as are `Bytes` (as the chars U+0000 to U+00FF).
+    pub fn symbols(self, str1:&str, str2:&str) -> (Vec<char>, Vec<char>)
+    {
+        match self {
+            Unit::Bytes => (str1.bytes().map(char::from).collect(), str2.bytes().map(char::from).collect()),
+            Unit::Chars => (str1.chars().collect(), str2.chars().collect()),
+            Unit::Graphemes => intern(str1.graphemes(true), str2.graphemes(true)),
+            Unit::Words => intern(str1.unicode_words(), str2.unicode_words())
+        }
+    }
+}
+
+/// Numbers the distinct units of both strings, the n-th distinct unit becomes the n-th char (skipping surrogates).
+fn intern<'a, I>(units1:I, units2:I) -> (Vec<char>, Vec<char>) where I: Iterator<Item=&'a str>
+{
+    let mut ids:HashMap<&'a str, char> = HashMap::new();
+    let mut symbol = |unit:&'a str| {
+        let n = ids.len() as u32;
+        *ids.entry(unit).or_insert_with(|| {
+            let n = if n >= 0xD800 { n + 0x800 } else { n };
+            char::from_u32(n).expect("too many distinct units to compare")
+        })
+    };
+
+    let s1:Vec<char> = units1.map(&mut symbol).collect();
+    let s2:Vec<char> = units2.map(&mut symbol).collect();
+    (s1, s2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn char_symbols() {
+        let (s1, s2) = Unit::Chars.symbols("añb", "");
+        assert_eq!(s1, vec!['a', 'ñ', 'b']);
+        assert!(s2.is_empty());
+    }
+
+    #[test]
+    fn byte_symbols() {
+        let (s1, s2) = Unit::Bytes.symbols("añ", "a");
+        assert_eq!(s1.len(), 3);
+        assert_eq!(s1[0], s2[0]);
+    }
+
+    #[test]
+    fn grapheme_symbols() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let (s1, s2) = Unit::Graphemes.symbols(&format!("a{}e\u{301}", family), family);
+        assert_eq!(s1.len(), 3);
+        assert_eq!(s2.len(), 1);
+        assert_eq!(s1[1], s2[0]);
+        assert!(s1[0] != s1[2]);
+    }
+
+    #[test]
+    fn word_symbols() {
+        let (s1, s2) = Unit::Words.symbols("Hello, world!", "world  hello");
+        assert_eq!(s1.len(), 2);
+        assert_eq!(s1[1], s2[0]);
+        assert!(s1[0] != s2[1]);
+    }
+}